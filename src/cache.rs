@@ -0,0 +1,55 @@
+//! Process-local memoization of [`crate::parse_fully`]'s result, keyed on the GLSL source text.
+//!
+//! A proc-macro crate is loaded once per build and re-invoked for every macro call site in that
+//! process, so a workspace that quotes the same (often large) shader source from many modules
+//! re-parses it from scratch every time, even though the text — and therefore the parsed AST —
+//! is identical. Tokenizing the parsed value back into Rust is cheap; the `nom` parse and its
+//! binary-search trailing-garbage check are what's worth skipping.
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Caps how many entries the cache will hold before it stops accepting new ones, so a build that
+/// quotes a huge number of *distinct* sources can't let this thread-local grow without bound.
+/// Once full, cache misses just reparse instead of evicting anything — simpler than an LRU, and
+/// this is a compile-time speedup, not a correctness requirement, so a miss is harmless.
+const MAX_ENTRIES: usize = 256;
+
+thread_local! {
+  static CACHE: RefCell<HashMap<(TypeId, String), Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// Return the cached `Ok(T)` for `src` if one is already in the cache, otherwise compute it with
+/// `parse` and cache it (unless the cache is already at [`MAX_ENTRIES`]). Errors are never
+/// cached, since they're never the expensive case this exists to avoid repeating.
+pub fn get_or_insert_with<T, E, F>(src: &str, parse: F) -> Result<T, E>
+where
+  T: Clone + 'static,
+  F: FnOnce() -> Result<T, E>,
+{
+  let key = (TypeId::of::<T>(), src.to_owned());
+
+  let cached = CACHE.with(|cache| {
+    cache
+      .borrow()
+      .get(&key)
+      .map(|value| value.downcast_ref::<T>().expect("cache type mismatch").clone())
+  });
+
+  if let Some(value) = cached {
+    return Ok(value);
+  }
+
+  let value = parse()?;
+
+  CACHE.with(|cache| {
+    let mut cache = cache.borrow_mut();
+
+    if cache.len() < MAX_ENTRIES {
+      cache.insert(key, Box::new(value.clone()));
+    }
+  });
+
+  Ok(value)
+}
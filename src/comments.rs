@@ -0,0 +1,126 @@
+//! Dropping the one kind of comment that survives into a macro's [`TokenStream`] input.
+//!
+//! `rustc`'s tokenizer throws away a `//` or `/* */` comment before a proc macro ever sees its
+//! input, so those are already "cleanly dropped" with no help from this crate. A `///`/`//!` doc
+//! comment is the one exception: it's lowered to a real `#[doc = "..."]`/`#![doc = "..."]`
+//! attribute attached to whatever follows, and every token inside that attribute shares the exact
+//! same span as the `#` that introduced it (there's no real source column for a compiler-
+//! synthesized token to point at). [`faithful_display`](proc_macro_faithful_display::faithful_display)
+//! reconstructs spacing from the gap between consecutive tokens' columns, so a repeated, static
+//! span like that panics it with a subtraction overflow the moment a later token's column is
+//! smaller than the one before it. GLSL has no use for a doc comment regardless -- it's dropped
+//! just like any other comment -- so the fix is to strip the attribute out before
+//! `faithful_display` ever sees it.
+//!
+//! That's only safe at the top level of the macro's own input, though. Stripping one nested
+//! inside a group (say, inside a function body) would mean rebuilding that group, and rebuilding
+//! a [`proc_macro2::Group`] collapses its distinct open/close delimiter spans into one (see
+//! [`crate::holes`]'s module doc for the same landmine) -- which panics `faithful_display` on
+//! that group's own closing delimiter, doc comment or not. So a nested one is reported as a
+//! `compile_error!` instead of risked.
+
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+
+/// Strip every top-level `#[doc = "..."]`/`#![doc = "..."]` attribute out of `input`, then render
+/// it with [`faithful_display`](proc_macro_faithful_display::faithful_display). Returns the
+/// rendered source on success, or a `compile_error!` token stream if a doc comment remains nested
+/// inside a group, where it can't be stripped safely (see the module doc).
+pub fn render(input: TokenStream) -> Result<String, proc_macro::TokenStream> {
+  let input = strip_top_level_doc_comments(input);
+
+  if let Some(span) = find_nested_doc_comment(&input) {
+    return Err(
+      quote::quote_spanned! { span =>
+        compile_error!("a `///`/`//!` doc comment isn't supported nested inside a block here; move it before the enclosing declaration, or use a `//`/`/* */` comment instead")
+      }
+      .into(),
+    );
+  }
+
+  Ok(format!(
+    "{}",
+    proc_macro_faithful_display::faithful_display(&proc_macro::TokenStream::from(input))
+  ))
+}
+
+fn strip_top_level_doc_comments(input: TokenStream) -> TokenStream {
+  let mut out = Vec::new();
+  let mut iter = input.into_iter().peekable();
+
+  while let Some(tt) = iter.next() {
+    if is_hash(&tt) {
+      if let Some(mut lookahead) = doc_attribute_lookahead(&iter) {
+        lookahead.next();
+        iter = lookahead;
+        continue;
+      }
+    }
+
+    out.push(tt);
+  }
+
+  out.into_iter().collect()
+}
+
+/// Find a `#[doc = "..."]`/`#![doc = "..."]` attribute nested one or more groups deep inside
+/// `input`, if any -- used to report a clear `compile_error!` instead of letting one crash
+/// `faithful_display` (see the module doc for why it can only be stripped at the top level).
+fn find_nested_doc_comment(input: &TokenStream) -> Option<Span> {
+  for tt in input.clone() {
+    let group = match tt {
+      TokenTree::Group(g) => g,
+      _ => continue,
+    };
+
+    let mut iter = group.stream().into_iter().peekable();
+
+    while let Some(inner) = iter.next() {
+      if is_hash(&inner) {
+        if let Some(mut lookahead) = doc_attribute_lookahead(&iter) {
+          return Some(lookahead.peek().expect("just matched a doc attribute").span());
+        }
+      }
+    }
+
+    if let Some(span) = find_nested_doc_comment(&group.stream()) {
+      return Some(span);
+    }
+  }
+
+  None
+}
+
+/// If `iter` starts with a `#[doc = "..."]`/`#![doc = "..."]` attribute's remaining tokens (the
+/// leading `#` having already been consumed), return an iterator positioned just before its
+/// closing bracket group, ready for the caller to skip past it.
+fn doc_attribute_lookahead(
+  iter: &std::iter::Peekable<proc_macro2::token_stream::IntoIter>,
+) -> Option<std::iter::Peekable<proc_macro2::token_stream::IntoIter>> {
+  let mut lookahead = iter.clone();
+
+  if matches!(lookahead.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '!') {
+    lookahead.next();
+  }
+
+  match lookahead.peek() {
+    Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Bracket && is_doc_attribute_body(&g.stream()) => {
+      Some(lookahead)
+    }
+    _ => None,
+  }
+}
+
+fn is_hash(tt: &TokenTree) -> bool {
+  matches!(tt, TokenTree::Punct(p) if p.as_char() == '#')
+}
+
+/// Whether `body` is exactly `doc = "<literal>"`, the shape `rustc` always lowers a `///`/`//!`
+/// doc comment's attribute body to.
+fn is_doc_attribute_body(body: &TokenStream) -> bool {
+  let mut iter = body.clone().into_iter();
+
+  matches!(iter.next(), Some(TokenTree::Ident(ref i)) if i == "doc")
+    && matches!(iter.next(), Some(TokenTree::Punct(ref p)) if p.as_char() == '=')
+    && matches!(iter.next(), Some(TokenTree::Literal(_)))
+    && iter.next().is_none()
+}
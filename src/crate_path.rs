@@ -0,0 +1,77 @@
+//! Support for an optional leading `@crate(path)` directive that redirects the generated code's
+//! hardcoded `::glsl::` prefix to a renamed or re-exported dependency.
+//!
+//! Generated code always writes the crate root as `::glsl::...` (an absolute path, so it can't be
+//! shadowed by a local `glsl` item — see [`crate::tokenize`]), so the path spliced in here is just
+//! the bare identifier that follows the leading `::`, not `::glsl` itself.
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::{quote, quote_spanned};
+
+/// Strip a leading `@crate(path)` directive off `input`, returning the crate path tokens to
+/// splice in its place (the bare `glsl` identifier if there's no directive) and the remaining tokens to treat as
+/// GLSL source. Returns a `compile_error!` token stream, spanned at the offending token, if a
+/// leading `@` isn't followed by a well-formed `crate(path)` directive — the same treatment every
+/// other user-facing error in this crate gets since `GlslQuoteError` stopped being raw `panic!`s
+/// (see [`crate::glsl_quote_error_to_compile_error`]), and this runs at the top of every public
+/// macro, so it's exercised on every single invocation.
+pub fn take_directive(input: TokenStream) -> Result<(TokenStream, TokenStream), proc_macro::TokenStream> {
+  let mut iter = input.into_iter().peekable();
+
+  let starts_with_at = matches!(iter.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '@');
+
+  if !starts_with_at {
+    return Ok((quote! { glsl }, iter.collect()));
+  }
+
+  let at_span = iter.next().unwrap().span();
+
+  match iter.next() {
+    Some(TokenTree::Ident(ref i)) if i == "crate" => {}
+    next => {
+      let span = next.map(|tt| tt.span()).unwrap_or(at_span);
+      return Err(
+        quote_spanned! { span => compile_error!("expected a `@crate(path)` directive after a leading `@`") }
+          .into(),
+      );
+    }
+  }
+
+  let path = match iter.next() {
+    Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis => g.stream(),
+    next => {
+      let span = next.map(|tt| tt.span()).unwrap_or(at_span);
+      return Err(quote_spanned! { span =>
+        compile_error!("`@crate(...)` expects a parenthesized crate path, e.g. `@crate(my_glsl)`")
+      }
+      .into());
+    }
+  };
+
+  if matches!(iter.peek(), Some(TokenTree::Punct(p)) if p.as_char() == ';') {
+    iter.next();
+  }
+
+  Ok((path, iter.collect()))
+}
+
+/// Replace every bare `glsl` identifier in `stream` with `path`. Generated code is never
+/// faithfully-displayed, so rebuilding groups here (unlike in [`crate::holes`]) doesn't risk
+/// corrupting anything.
+pub fn rewrite(stream: TokenStream, path: &TokenStream) -> TokenStream {
+  stream
+    .into_iter()
+    .flat_map(|tt| match tt {
+      TokenTree::Ident(ref i) if i == "glsl" => path.clone().into_iter().collect::<Vec<_>>(),
+
+      TokenTree::Group(g) => {
+        let inner = rewrite(g.stream(), path);
+        let mut rewritten = proc_macro2::Group::new(g.delimiter(), inner);
+        rewritten.set_span(g.span());
+        vec![TokenTree::Group(rewritten)]
+      }
+
+      other => vec![other],
+    })
+    .collect()
+}
@@ -0,0 +1,78 @@
+//! A nesting-depth limit, guarding against a stack overflow on a pathologically (or
+//! adversarially) nested expression — thousands of parentheses deep, say — which would otherwise
+//! crash the build with a bare segfault instead of a readable error. Checked twice, since each
+//! side recurses on its own and neither can see the other's stack usage: once on the raw source
+//! text before it's handed to [`glsl`]'s parser (whose own recursive-descent isn't ours to
+//! instrument), and once again in [`crate::tokenize::tokenize_expr`], which does recurse through
+//! code we own.
+
+use std::cell::Cell;
+
+/// The default maximum nesting depth, used when `GLSL_QUASIQUOTE_MAX_EXPR_DEPTH` isn't set.
+/// Comfortably beyond any hand-written shader expression while staying well clear of the depths
+/// that actually overflow a `rustc` worker's stack.
+pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+/// The configured limit: `GLSL_QUASIQUOTE_MAX_EXPR_DEPTH` if it's set to a valid `usize`,
+/// otherwise [`DEFAULT_MAX_DEPTH`].
+pub fn max_depth() -> usize {
+  std::env::var("GLSL_QUASIQUOTE_MAX_EXPR_DEPTH")
+    .ok()
+    .and_then(|v| v.parse().ok())
+    .unwrap_or(DEFAULT_MAX_DEPTH)
+}
+
+/// The deepest nesting of `(`, `{`, or `[` found anywhere in `source`, not distinguishing which
+/// kind of bracket it is — an over-limit expression risks overflowing the stack regardless of
+/// which bracket it's built from.
+pub fn max_bracket_depth(source: &str) -> usize {
+  let mut depth = 0usize;
+  let mut max = 0usize;
+
+  for c in source.chars() {
+    match c {
+      '(' | '{' | '[' => {
+        depth += 1;
+        max = max.max(depth);
+      }
+      ')' | '}' | ']' => depth = depth.saturating_sub(1),
+      _ => {}
+    }
+  }
+
+  max
+}
+
+thread_local! {
+  // One macro invocation uses one thread for its whole expansion (see `crate::holes`), so a
+  // thread-local counter is enough to track `tokenize_expr`'s live recursion depth.
+  static TOKENIZE_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// An RAII guard marking one more level of live [`tokenize_expr`](crate::tokenize::tokenize_expr)
+/// recursion for as long as it's alive, releasing that level again on drop.
+pub struct Guard(());
+
+impl Guard {
+  /// Enter one more level of recursion, or `None` if that would exceed [`max_depth`].
+  pub fn enter() -> Option<Guard> {
+    let depth = TOKENIZE_DEPTH.with(|d| {
+      let next = d.get() + 1;
+      d.set(next);
+      next
+    });
+
+    if depth > max_depth() {
+      TOKENIZE_DEPTH.with(|d| d.set(d.get() - 1));
+      return None;
+    }
+
+    Some(Guard(()))
+  }
+}
+
+impl Drop for Guard {
+  fn drop(&mut self) {
+    TOKENIZE_DEPTH.with(|d| d.set(d.get() - 1));
+  }
+}
@@ -0,0 +1,698 @@
+//! Preprocessing that turns a `#name` interpolation hole into a GLSL-legal placeholder
+//! identifier the [`glsl`] parser can swallow, while remembering the Rust identifier that was
+//! spliced in so [`tokenize`](crate::tokenize) can emit it back out at the right spot.
+//!
+//! This works on the already-rendered GLSL source string (i.e. after
+//! [`faithful_display`](proc_macro_faithful_display::faithful_display) has turned the macro's
+//! input tokens back into text) rather than on the token tree itself: rebuilding a
+//! `proc_macro2::Group` to splice in a placeholder collapses its open/close delimiter spans into
+//! one, which corrupts `faithful_display`'s spacing reconstruction for everything nested inside
+//! it. Substituting text is immune to that, since it never needs to touch a span.
+
+use proc_macro2::{Ident, Span};
+use std::cell::RefCell;
+
+/// Identifiers under this prefix are placeholders inserted by [`extract_holes`]; nothing a user
+/// writes in GLSL is expected to collide with it.
+pub const HOLE_PREFIX: &str = "__glsl_quasiquote_hole_";
+
+/// Identifiers under this prefix name a placeholder *function definition* standing in for a
+/// `#(#name)` splice — see [`splice`] and [`crate::tokenize::tokenize_translation_unit`].
+pub const SPLICE_PREFIX: &str = "__glsl_quasiquote_splice_";
+
+/// Type names under this prefix name a placeholder `subroutine(..)` storage qualifier standing in
+/// for a `#[#name]` qualifier splice — see [`qualifier`] and
+/// [`crate::tokenize::tokenize_fully_specified_type`].
+pub const QUALIFIER_PREFIX: &str = "__glsl_quasiquote_qualifier_";
+
+/// Type names under this prefix name a placeholder [`TypeName`](glsl::syntax::TypeName) standing
+/// in for a `#{#name}` type-position splice — see [`type_splice`] and
+/// [`crate::tokenize::tokenize_type_specifier`]. Unlike [`QUALIFIER_PREFIX`], a bare identifier is
+/// already syntactically legal as a `TypeSpecifierNonArray::TypeName` everywhere a type goes, so
+/// no keyword trick is needed to smuggle it through parsing.
+pub const TYPE_PREFIX: &str = "__glsl_quasiquote_type_";
+
+/// Identifier names under this prefix name a placeholder `subroutine(..)` storage qualifier
+/// standing in for a `#<#name>` precision-qualifier splice — see [`precision_splice`] and
+/// [`crate::tokenize::precision_qualifier_splice`]. Reuses the same `subroutine(..)` trick as
+/// [`QUALIFIER_PREFIX`] (a bare identifier isn't syntactically legal as a `TypeQualifierSpec`
+/// either), under its own prefix so the two splice kinds — "replace the whole qualifier" versus
+/// "replace one precision spec within it" — can't be confused for each other once parsed.
+pub const PRECISION_PREFIX: &str = "__glsl_quasiquote_precision_";
+
+/// Identifier names under this prefix name a placeholder `subroutine(..)` storage qualifier
+/// standing in for a `#|#name|` storage-qualifier splice — see [`storage_splice`] and
+/// [`crate::tokenize::storage_qualifier_splice`]. Reuses the same `subroutine(..)` trick as
+/// [`QUALIFIER_PREFIX`] and [`PRECISION_PREFIX`] (a bare identifier isn't syntactically legal as a
+/// `TypeQualifierSpec` either), under its own prefix and its own bracket pair (`(`, `[`, `{`, `<`
+/// are already spoken for by the other splice kinds) so it can't be confused with either of them
+/// once parsed.
+pub const STORAGE_PREFIX: &str = "__glsl_quasiquote_storage_";
+
+/// Type names under this prefix name a placeholder [`TypeName`](glsl::syntax::TypeName) standing
+/// in for a `#~#name~` fully-specified-type splice — see [`fully_specified_type_splice`] and
+/// [`crate::tokenize::tokenize_fully_specified_type`]. Unlike [`TYPE_PREFIX`] (which only replaces
+/// the `TypeSpecifier`, leaving any separately-written qualifier alone), this one stands in for
+/// the *whole* [`FullySpecifiedType`](glsl::syntax::FullySpecifiedType) — qualifier included — so
+/// it's kept under its own prefix rather than reusing `TYPE_PREFIX`, even though both are plain
+/// identifier placeholders with no keyword trick needed.
+pub const FULLY_SPECIFIED_TYPE_PREFIX: &str = "__glsl_quasiquote_fully_specified_type_";
+
+/// Identifier names under this prefix name a placeholder declaration standing in for a bare
+/// `#name` hole found right where [`glsl_function!`](crate::glsl_function)'s fixed grammar expects
+/// its whole [`CompoundStatement`](glsl::syntax::CompoundStatement) body — see [`compound_splice`]
+/// and [`crate::tokenize::compound_statement_splice`]. A `CompoundStatement` has no bare-identifier
+/// form of its own to smuggle a placeholder through as (unlike a declaration list or a call's
+/// argument list, there's no position inside `{ }` a whole extra statement can stand in for
+/// without already being one), so [`extract_holes_for_function_prototype`] wraps the name in a
+/// synthetic `{ NAME; }` itself before parsing — which itself parses as a one-statement body
+/// declaring a (nameless) type by that name, not as an expression statement, since GLSL has no
+/// bare-identifier expression statement of its own either. Kept under its own prefix, rather than
+/// reusing [`HOLE_PREFIX`], so this can be told apart from a literal one-statement body that
+/// happens to declare a real type by name alone (`void f() { Light; }`) once both have parsed down
+/// to the same shape.
+pub const COMPOUND_PREFIX: &str = "__glsl_quasiquote_compound_";
+
+/// `#version` numbers at or above this value are a placeholder standing in for a `#version #name`
+/// splice — see [`version`] and [`crate::tokenize::tokenize_preprocessor_version`]. No real GLSL
+/// version is anywhere near this range, and unlike the profile keyword (a closed, payload-less
+/// `core`/`compatibility`/`es` set), the version number is an arbitrary `u16`, so it's the only
+/// part of a `#version` directive a placeholder *value* (rather than a placeholder identifier)
+/// can stand in for.
+pub const VERSION_HOLE_BASE: u16 = 60000;
+
+/// Keywords that follow a `#` as a GLSL preprocessor directive rather than an interpolation
+/// hole. `#version`, `#define foo`, etc. must keep working in `glsl!` as plain GLSL, so we only
+/// treat `#name` as a splice when `name` isn't one of these.
+const DIRECTIVE_KEYWORDS: &[&str] = &[
+  "version", "extension", "define", "undef", "if", "ifdef", "ifndef", "else", "elif", "endif",
+  "error", "pragma", "include", "line",
+];
+
+/// Where we are relative to a literal `#version` directive keyword, tracked character-by-character
+/// as [`extract_holes`] forwards non-hole text verbatim.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum VersionState {
+  /// Not inside a `#version` directive (or past the point where its number could appear).
+  Idle,
+  /// Just recognized the `version` keyword; still forwarding its own letters, which must not be
+  /// mistaken for the start of whitespace-then-number.
+  SeenKeyword,
+  /// Past `#version`'s keyword and its trailing whitespace; the next `#name` hole (if any, before
+  /// a literal digit shows up instead) is the version number.
+  AwaitingNumber,
+}
+
+/// Where we are relative to a literal `struct` keyword, tracked the same way as [`VersionState`]:
+/// whether the next `{...}` brace pair opened is a struct's field-list body, so a `#(#name)`
+/// splice found inside it (see [`extract_holes`]) is recognized as a field-list splice rather
+/// than the top-level declaration-list splice it'd otherwise be taken for.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum StructState {
+  /// Not right after a `struct` keyword.
+  Idle,
+  /// Just recognized the `struct` keyword; still waiting for the `{` that opens its field list
+  /// (an optional type name, e.g. `struct Foo {`, may come first).
+  AwaitingBody,
+}
+
+thread_local! {
+  // One macro invocation uses one thread for its whole expansion, so a thread-local table is
+  // enough to carry the holes from preprocessing through to tokenization without threading an
+  // extra argument through every `tokenize_*` function.
+  static HOLES: RefCell<Vec<Ident>> = const { RefCell::new(Vec::new()) };
+
+  // Same idea, for `#(#name)` top-level splices (see [`splice`]); kept separate from `HOLES` so
+  // the two can't be confused by index.
+  static SPLICES: RefCell<Vec<Ident>> = const { RefCell::new(Vec::new()) };
+
+  // Same idea, for `#[#name]` qualifier splices (see [`qualifier`]).
+  static QUALIFIERS: RefCell<Vec<Ident>> = const { RefCell::new(Vec::new()) };
+
+  // Same idea, for `#{#name}` type-position splices (see [`type_splice`]).
+  static TYPES: RefCell<Vec<Ident>> = const { RefCell::new(Vec::new()) };
+
+  // Same idea, for `#version #name` splices (see [`version`]).
+  static VERSIONS: RefCell<Vec<Ident>> = const { RefCell::new(Vec::new()) };
+
+  // Same idea, for `#<#name>` precision-qualifier splices (see [`precision_splice`]).
+  static PRECISIONS: RefCell<Vec<Ident>> = const { RefCell::new(Vec::new()) };
+
+  // Same idea, for `#|#name|` storage-qualifier splices (see [`storage_splice`]).
+  static STORAGES: RefCell<Vec<Ident>> = const { RefCell::new(Vec::new()) };
+
+  // Same idea, for `#~#name~` fully-specified-type splices (see [`fully_specified_type_splice`]).
+  static FULLY_SPECIFIED_TYPES: RefCell<Vec<Ident>> = const { RefCell::new(Vec::new()) };
+
+  // Same idea, for a whole-`CompoundStatement` splice found in a function body position (see
+  // [`compound_splice`]).
+  static COMPOUNDS: RefCell<Vec<Ident>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Replace every `#name` occurrence in `source` with a placeholder identifier, recording `name`
+/// so it can be recovered later by [`hole`]. A `#(#name)` occurrence is replaced with a
+/// placeholder *external declaration* instead (a `#name` hole isn't syntactically valid on its
+/// own at the top level), recording `name` so it can be recovered by [`splice`] — unless it's
+/// inside a `struct`'s field list (tracked the same way as the `#version` case below), in which
+/// case the placeholder is a field declaration instead, or inside a parenthesized argument list
+/// (e.g. a function call's or array constructor's arguments), in which case the placeholder is a
+/// bare identifier instead, since that's what's syntactically valid in each of those spots. A
+/// `#[#name]` occurrence is replaced with a placeholder *storage qualifier* (see
+/// [`qualifier`]), since a type qualifier is its own fixed set of keywords rather than something
+/// a bare identifier can stand in for. A `#name` occurrence right after `#version` is replaced
+/// with a placeholder *number* instead (see [`version`]), since the version number is the one
+/// part of a `#version` directive an identifier-shaped placeholder can't stand in for. A
+/// `#{#name}` occurrence is replaced with a placeholder *type name* (see [`type_splice`]), so
+/// `name`'s whole [`TypeSpecifier`](glsl::syntax::TypeSpecifier) value can be spliced in directly
+/// wherever a type goes, bypassing re-serialization into GLSL source the way a plain `#name`
+/// identifier hole would require. A `#<#name>` occurrence is replaced with a placeholder
+/// *storage qualifier* too (see [`precision_splice`]), the same `subroutine(..)` trick
+/// [`qualifier`] uses for `#[#name]`, since a precision qualifier is also a closed set of keywords
+/// — kept under its own prefix so it splices in as a single
+/// [`TypeQualifierSpec`](glsl::syntax::TypeQualifierSpec) within the qualifier list rather than
+/// replacing the whole list the way `#[#name]` does. A `#|#name|` occurrence is replaced with a
+/// placeholder *storage qualifier* the same way (see [`storage_splice`]), under its own prefix and
+/// bracket pair so it splices in as a `StorageQualifier` spec without being confused with a
+/// precision splice. A `#~#name~` occurrence is replaced with a placeholder *type name* the same
+/// way `#{#name}` is (see [`fully_specified_type_splice`]), but standing in for the whole
+/// [`FullySpecifiedType`](glsl::syntax::FullySpecifiedType) — qualifier and all — rather than just
+/// the `TypeSpecifier` within it. A literal `##` is an escape for a single literal `#` — checked before any of
+/// the above, so it takes priority everywhere a hole could otherwise start, including right before
+/// a directive keyword (`##version` forwards a literal `#version`, not a hole) and right before
+/// `#version`'s own number slot.
+pub fn extract_holes(source: &str) -> String {
+  extract_holes_impl(source, false)
+}
+
+/// Like [`extract_holes`], but additionally recognizes a `#(#name)` splice found inside a
+/// function prototype's parameter list (its first top-level, un-nested `(...)`) as a parameter
+/// splice (see [`splice`] and [`crate::tokenize::function_parameter_splice`]) rather than the
+/// call-argument splice [`extract_holes`] would otherwise read it as. Used only by
+/// [`crate::glsl_function`], whose fixed `ReturnType name(params) { body }` grammar guarantees
+/// nothing can precede that first top-level paren — a guarantee [`extract_holes`] can't rely on
+/// in general, since e.g. a top-level declaration's initializer (`float x[3] = float[](#(#xs));`)
+/// is a genuine call-argument splice sitting at the same nesting depth.
+pub fn extract_holes_for_function_prototype(source: &str) -> String {
+  extract_holes_impl(source, true)
+}
+
+fn extract_holes_impl(source: &str, in_function_prototype: bool) -> String {
+  let source = strip_trailing_list_commas(source);
+
+  HOLES.with(|holes| holes.borrow_mut().clear());
+  SPLICES.with(|splices| splices.borrow_mut().clear());
+  QUALIFIERS.with(|qualifiers| qualifiers.borrow_mut().clear());
+  VERSIONS.with(|versions| versions.borrow_mut().clear());
+  TYPES.with(|types| types.borrow_mut().clear());
+  PRECISIONS.with(|precisions| precisions.borrow_mut().clear());
+  STORAGES.with(|storages| storages.borrow_mut().clear());
+  FULLY_SPECIFIED_TYPES.with(|types| types.borrow_mut().clear());
+  COMPOUNDS.with(|compounds| compounds.borrow_mut().clear());
+
+  let mut output = String::with_capacity(source.len());
+  let mut chars = source.chars().peekable();
+
+  // Only meaningful when `in_function_prototype` is set: tracks whether we're inside the
+  // parameter list (the first top-level `(...)` pair) versus past it (e.g. inside the function's
+  // body), so a `#(#name)` splice found there can be read as a parameter-list splice.
+  let mut in_param_list = false;
+  let mut param_list_done = false;
+
+  // Only meaningful when `in_function_prototype` is set: true for the single slot right after the
+  // parameter list's closing `)`, where a whole-`CompoundStatement` splice (see
+  // [`COMPOUND_PREFIX`]) is recognized if that's what comes next. Cleared the moment anything
+  // else (whitespace aside) is forwarded, since that slot is only ever the very next token.
+  let mut awaiting_compound_body = false;
+
+  // Tracks where we are relative to a literal `#version` directive keyword, so the hole (if any)
+  // that follows its whitespace is read as the version *number* rather than an ordinary
+  // identifier hole.
+  let mut version_state = VersionState::Idle;
+
+  // Tracks where we are relative to a literal `struct` keyword, so a `#(#name)` splice found once
+  // its `{...}` field list is open is recognized as a field-list splice (see `struct_body_depth`
+  // below for the brace tracking itself).
+  let mut struct_state = StructState::Idle;
+
+  // >0 while forwarding text that's inside a struct's field-list body (possibly nested, for an
+  // inline struct type used as another struct's field). A struct body can't contain any other
+  // kind of brace pair, so plain depth counting is enough to find its extent.
+  let mut struct_body_depth: usize = 0;
+
+  // >0 while forwarding text that's inside any literal (non-hole) parenthesized list — a function
+  // call's or array constructor's arguments, most commonly — so a `#(#name)` splice found there is
+  // recognized as an argument-list splice (see `crate::tokenize::tokenize_expr`'s `FunCall`
+  // branch) rather than the top-level declaration-list splice it'd otherwise be taken for: a bare
+  // identifier is already syntactically legal wherever a single argument goes, the same way it is
+  // for a plain `#name` hole in expression position.
+  let mut paren_depth: usize = 0;
+
+  // True right after a character that can't continue an identifier (or at the start of input),
+  // i.e. we're at a word boundary and about to read a fresh word — used to recognize the `struct`
+  // keyword without mistaking e.g. `construct` for it.
+  let mut at_word_boundary = true;
+
+  while let Some(c) = chars.next() {
+    if c != '#' {
+      if awaiting_compound_body && !c.is_whitespace() {
+        awaiting_compound_body = false;
+      }
+
+      version_state = match version_state {
+        VersionState::SeenKeyword if c.is_whitespace() => VersionState::AwaitingNumber,
+        VersionState::AwaitingNumber if !c.is_whitespace() => VersionState::Idle,
+        other => other,
+      };
+
+      let is_ident_char = c == '_' || c.is_alphanumeric();
+
+      if is_ident_char && at_word_boundary {
+        let word: String = std::iter::once(c)
+          .chain(peek_ident_tail(&chars).chars())
+          .collect();
+
+        if word == "struct" {
+          struct_state = StructState::AwaitingBody;
+        }
+      }
+
+      at_word_boundary = !is_ident_char;
+
+      if struct_state == StructState::AwaitingBody && c == '{' {
+        struct_state = StructState::Idle;
+        struct_body_depth += 1;
+      } else if struct_body_depth > 0 {
+        if c == '{' {
+          struct_body_depth += 1;
+        } else if c == '}' {
+          struct_body_depth -= 1;
+        }
+      }
+
+      if c == '(' {
+        if in_function_prototype && !param_list_done && paren_depth == 0 {
+          in_param_list = true;
+        }
+
+        paren_depth += 1;
+      } else if c == ')' && paren_depth > 0 {
+        paren_depth -= 1;
+
+        if in_param_list && paren_depth == 0 {
+          in_param_list = false;
+          param_list_done = true;
+          awaiting_compound_body = true;
+        }
+      }
+
+      output.push(c);
+      continue;
+    }
+
+    if chars.peek() == Some(&'#') {
+      // `##` escapes to a single literal `#`, the same way a quasiquote typically lets you escape
+      // its own splice marker — needed for anything that wants a real `#` in the reconstructed
+      // source (a computed `#pragma`, say) without it being mistaken for the start of a hole.
+      chars.next();
+      output.push('#');
+      continue;
+    }
+
+    if awaiting_compound_body {
+      awaiting_compound_body = false;
+
+      if let Some((name, rest)) = peek_plain_hole(&chars) {
+        chars = rest;
+
+        let index = COMPOUNDS.with(|compounds| {
+          let mut compounds = compounds.borrow_mut();
+          compounds.push(Ident::new(&name, Span::call_site()));
+          compounds.len() - 1
+        });
+
+        output.push('{');
+        output.push_str(COMPOUND_PREFIX);
+        output.push_str(&index.to_string());
+        output.push_str(";}");
+        continue;
+      }
+    }
+
+    if version_state == VersionState::AwaitingNumber {
+      if let Some((name, rest)) = peek_plain_hole(&chars) {
+        chars = rest;
+        version_state = VersionState::Idle;
+
+        let index = VERSIONS.with(|versions| {
+          let mut versions = versions.borrow_mut();
+          versions.push(Ident::new(&name, Span::call_site()));
+          versions.len() - 1
+        });
+
+        output.push_str(&(VERSION_HOLE_BASE as usize + index).to_string());
+        continue;
+      }
+    }
+
+    if let Some((name, rest)) = peek_bracketed_hole(&chars, '(', ')') {
+      chars = rest;
+
+      let index = SPLICES.with(|splices| {
+        let mut splices = splices.borrow_mut();
+        splices.push(Ident::new(&name, Span::call_site()));
+        splices.len() - 1
+      });
+
+      if in_param_list {
+        // Inside a function prototype's parameter list (see `extract_holes_for_function_prototype`),
+        // a placeholder has to parse as a named parameter declaration (a lone identifier declared
+        // `void`) rather than as a bare call argument — see
+        // `crate::tokenize::function_parameter_splice`.
+        output.push_str("void ");
+        output.push_str(SPLICE_PREFIX);
+        output.push_str(&index.to_string());
+      } else if struct_body_depth > 0 {
+        // Inside a struct's field list, a placeholder has to parse as a `StructFieldSpecifier`
+        // (a lone identifier declared `void`) rather than as an external declaration — see
+        // `crate::tokenize::tokenize_struct_non_declaration`.
+        output.push_str("void ");
+        output.push_str(SPLICE_PREFIX);
+        output.push_str(&index.to_string());
+        output.push(';');
+      } else if paren_depth > 0 {
+        // Inside a parenthesized argument list, a placeholder has to parse as a bare identifier
+        // (a single assignment-expr) rather than a declaration — see
+        // `crate::tokenize::tokenize_expr`'s `FunCall` branch.
+        output.push_str(SPLICE_PREFIX);
+        output.push_str(&index.to_string());
+      } else {
+        output.push_str("void ");
+        output.push_str(SPLICE_PREFIX);
+        output.push_str(&index.to_string());
+        output.push_str("(){}");
+      }
+
+      continue;
+    }
+
+    if let Some((name, rest)) = peek_bracketed_hole(&chars, '[', ']') {
+      chars = rest;
+
+      let index = QUALIFIERS.with(|qualifiers| {
+        let mut qualifiers = qualifiers.borrow_mut();
+        qualifiers.push(Ident::new(&name, Span::call_site()));
+        qualifiers.len() - 1
+      });
+
+      output.push_str("subroutine(");
+      output.push_str(QUALIFIER_PREFIX);
+      output.push_str(&index.to_string());
+      output.push(')');
+      continue;
+    }
+
+    if let Some((name, rest)) = peek_bracketed_hole(&chars, '{', '}') {
+      chars = rest;
+
+      let index = TYPES.with(|types| {
+        let mut types = types.borrow_mut();
+        types.push(Ident::new(&name, Span::call_site()));
+        types.len() - 1
+      });
+
+      output.push_str(TYPE_PREFIX);
+      output.push_str(&index.to_string());
+      continue;
+    }
+
+    if let Some((name, rest)) = peek_bracketed_hole(&chars, '<', '>') {
+      chars = rest;
+
+      let index = PRECISIONS.with(|precisions| {
+        let mut precisions = precisions.borrow_mut();
+        precisions.push(Ident::new(&name, Span::call_site()));
+        precisions.len() - 1
+      });
+
+      output.push_str("subroutine(");
+      output.push_str(PRECISION_PREFIX);
+      output.push_str(&index.to_string());
+      output.push(')');
+      continue;
+    }
+
+    if let Some((name, rest)) = peek_bracketed_hole(&chars, '|', '|') {
+      chars = rest;
+
+      let index = STORAGES.with(|storages| {
+        let mut storages = storages.borrow_mut();
+        storages.push(Ident::new(&name, Span::call_site()));
+        storages.len() - 1
+      });
+
+      output.push_str("subroutine(");
+      output.push_str(STORAGE_PREFIX);
+      output.push_str(&index.to_string());
+      output.push(')');
+      continue;
+    }
+
+    if let Some((name, rest)) = peek_bracketed_hole(&chars, '~', '~') {
+      chars = rest;
+
+      let index = FULLY_SPECIFIED_TYPES.with(|types| {
+        let mut types = types.borrow_mut();
+        types.push(Ident::new(&name, Span::call_site()));
+        types.len() - 1
+      });
+
+      output.push_str(FULLY_SPECIFIED_TYPE_PREFIX);
+      output.push_str(&index.to_string());
+      continue;
+    }
+
+    let name = peek_ident(&chars);
+
+    if name.is_empty() || DIRECTIVE_KEYWORDS.contains(&name.as_str()) {
+      if name == "version" {
+        version_state = VersionState::SeenKeyword;
+      }
+
+      output.push('#');
+      continue;
+    }
+
+    for _ in 0..name.chars().count() {
+      chars.next();
+    }
+
+    let index = HOLES.with(|holes| {
+      let mut holes = holes.borrow_mut();
+      holes.push(Ident::new(&name, Span::call_site()));
+      holes.len() - 1
+    });
+
+    output.push_str(HOLE_PREFIX);
+    output.push_str(&index.to_string());
+  }
+
+  output
+}
+
+/// Drop a trailing comma from any comma-separated list immediately followed (modulo whitespace) by
+/// the `)` or `;` that closes it — a function's parameter list, a struct field's shared-type
+/// declarator list (`float x, y,;`), a call's argument list, and so on. GLSL itself rejects one
+/// there; this is a quasiquote-only leniency, applied here in the reconstruction step before the
+/// source is handed to the real parser, so a list built up by string concatenation doesn't need to
+/// special-case its last element to avoid a trailing separator.
+fn strip_trailing_list_commas(source: &str) -> String {
+  let mut output = String::with_capacity(source.len());
+  let mut chars = source.chars().peekable();
+
+  while let Some(c) = chars.next() {
+    if c == ',' {
+      let mut lookahead = chars.clone();
+      skip_whitespace(&mut lookahead);
+
+      if matches!(lookahead.peek(), Some(')') | Some(';')) {
+        continue;
+      }
+    }
+
+    output.push(c);
+  }
+
+  output
+}
+
+/// If `chars` (positioned right after a `#`) starts a bare `#name` hole, consume it and return
+/// `name` along with the position right after it. Otherwise, return `None` and leave `chars`
+/// untouched.
+fn peek_plain_hole<'a>(
+  chars: &std::iter::Peekable<std::str::Chars<'a>>,
+) -> Option<(String, std::iter::Peekable<std::str::Chars<'a>>)> {
+  let mut lookahead = chars.clone();
+  let name = peek_ident(&lookahead);
+
+  if name.is_empty() {
+    return None;
+  }
+
+  for _ in 0..name.chars().count() {
+    lookahead.next();
+  }
+
+  Some((name, lookahead))
+}
+
+/// If `chars` (positioned right after a `#`) starts a `#<open>#name<close>` marker (e.g.
+/// `#(#name)` or `#[#name]`), consume it and return `name` along with the position right after
+/// `close`. Otherwise, return `None` and leave `chars` untouched.
+fn peek_bracketed_hole<'a>(
+  chars: &std::iter::Peekable<std::str::Chars<'a>>,
+  open: char,
+  close: char,
+) -> Option<(String, std::iter::Peekable<std::str::Chars<'a>>)> {
+  let mut lookahead = chars.clone();
+
+  if lookahead.next() != Some(open) {
+    return None;
+  }
+
+  skip_whitespace(&mut lookahead);
+
+  if lookahead.next() != Some('#') {
+    return None;
+  }
+
+  let name = peek_ident(&lookahead);
+
+  if name.is_empty() {
+    return None;
+  }
+
+  for _ in 0..name.chars().count() {
+    lookahead.next();
+  }
+
+  skip_whitespace(&mut lookahead);
+
+  if lookahead.next() != Some(close) {
+    return None;
+  }
+
+  Some((name, lookahead))
+}
+
+/// Consume leading whitespace from `chars`.
+fn skip_whitespace(chars: &mut std::iter::Peekable<std::str::Chars>) {
+  while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+    chars.next();
+  }
+}
+
+/// Read the identifier starting at the front of `chars` without consuming it.
+fn peek_ident(chars: &std::iter::Peekable<std::str::Chars>) -> String {
+  let mut lookahead = chars.clone();
+  let mut name = String::new();
+
+  match lookahead.peek() {
+    Some(&c) if c == '_' || c.is_alphabetic() => {
+      name.push(c);
+      lookahead.next();
+    }
+    _ => return name,
+  }
+
+  while let Some(&c) = lookahead.peek() {
+    if c == '_' || c.is_alphanumeric() {
+      name.push(c);
+      lookahead.next();
+    } else {
+      break;
+    }
+  }
+
+  name
+}
+
+/// Read the remainder of an identifier whose first character has already been consumed, without
+/// consuming it from `chars`. Used to check whether the character just read starts the keyword
+/// `struct`.
+fn peek_ident_tail(chars: &std::iter::Peekable<std::str::Chars>) -> String {
+  let mut lookahead = chars.clone();
+  let mut rest = String::new();
+
+  while let Some(&c) = lookahead.peek() {
+    if c == '_' || c.is_alphanumeric() {
+      rest.push(c);
+      lookahead.next();
+    } else {
+      break;
+    }
+  }
+
+  rest
+}
+
+/// If `name` is a hole placeholder, return the Rust identifier it stands for.
+pub fn hole(name: &str) -> Option<Ident> {
+  let index: usize = name.strip_prefix(HOLE_PREFIX)?.parse().ok()?;
+  HOLES.with(|holes| holes.borrow().get(index).cloned())
+}
+
+/// If `name` is a `#(#name)` splice placeholder's function name (or, in a parenthesized argument
+/// list, its bare identifier), return the Rust identifier it stands for.
+pub fn splice(name: &str) -> Option<Ident> {
+  let index: usize = name.strip_prefix(SPLICE_PREFIX)?.parse().ok()?;
+  SPLICES.with(|splices| splices.borrow().get(index).cloned())
+}
+
+/// If `name` is a `#[#name]` qualifier splice placeholder's `subroutine(..)` type name, return
+/// the Rust identifier it stands for.
+pub fn qualifier(name: &str) -> Option<Ident> {
+  let index: usize = name.strip_prefix(QUALIFIER_PREFIX)?.parse().ok()?;
+  QUALIFIERS.with(|qualifiers| qualifiers.borrow().get(index).cloned())
+}
+
+/// If `version` is a `#version #name` splice placeholder's number, return the Rust identifier it
+/// stands for.
+pub fn version(version: u16) -> Option<Ident> {
+  let index: usize = (version.checked_sub(VERSION_HOLE_BASE)?) as usize;
+  VERSIONS.with(|versions| versions.borrow().get(index).cloned())
+}
+
+/// If `name` is a `#{#name}` type-position splice placeholder's type name, return the Rust
+/// identifier it stands for.
+pub fn type_splice(name: &str) -> Option<Ident> {
+  let index: usize = name.strip_prefix(TYPE_PREFIX)?.parse().ok()?;
+  TYPES.with(|types| types.borrow().get(index).cloned())
+}
+
+/// If `name` is a `#<#name>` precision-qualifier splice placeholder's `subroutine(..)` type name,
+/// return the Rust identifier it stands for.
+pub fn precision_splice(name: &str) -> Option<Ident> {
+  let index: usize = name.strip_prefix(PRECISION_PREFIX)?.parse().ok()?;
+  PRECISIONS.with(|precisions| precisions.borrow().get(index).cloned())
+}
+
+/// If `name` is a `#|#name|` storage-qualifier splice placeholder's `subroutine(..)` type name,
+/// return the Rust identifier it stands for.
+pub fn storage_splice(name: &str) -> Option<Ident> {
+  let index: usize = name.strip_prefix(STORAGE_PREFIX)?.parse().ok()?;
+  STORAGES.with(|storages| storages.borrow().get(index).cloned())
+}
+
+/// If `name` is a `#~#name~` fully-specified-type splice placeholder's type name, return the Rust
+/// identifier it stands for.
+pub fn fully_specified_type_splice(name: &str) -> Option<Ident> {
+  let index: usize = name.strip_prefix(FULLY_SPECIFIED_TYPE_PREFIX)?.parse().ok()?;
+  FULLY_SPECIFIED_TYPES.with(|types| types.borrow().get(index).cloned())
+}
+
+/// If `name` is a whole-`CompoundStatement` splice placeholder's (nameless) declared type name
+/// (see [`COMPOUND_PREFIX`]), return the Rust identifier it stands for.
+pub fn compound_splice(name: &str) -> Option<Ident> {
+  let index: usize = name.strip_prefix(COMPOUND_PREFIX)?.parse().ok()?;
+  COMPOUNDS.with(|compounds| compounds.borrow().get(index).cloned())
+}
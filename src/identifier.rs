@@ -0,0 +1,86 @@
+//! Validation for the identifier text that flows through
+//! [`tokenize_identifier`](crate::tokenize::tokenize_identifier), GLSL's own grammar (and the
+//! `glsl` crate's [`Identifier::new`](glsl::syntax::Identifier::new)) only rejects a digit-led or
+//! non-alphanumeric name, not a reserved word, so something like `int for;` parses just fine here
+//! and only fails once a real GLSL compiler (or whatever downstream tool transpiles this crate's
+//! output) sees it — at which point the error has nothing to do with the identifier anymore and
+//! is much harder to place. Catching it here, at the one spot identifiers actually get
+//! interpolated, turns that into an immediate, specific error instead.
+
+/// GLSL's non-type keywords, taken from the "Keywords" section of the OpenGL Shading Language
+/// spec (4.60, §3.6) and the GLSL ES 3.2 spec's equivalent section — the union of both, since
+/// this crate doesn't track which GLSL version/profile a given `glsl!` targets. Type keywords
+/// (`float`, `vec3`, `sampler2D`, ...) and `highp`/`mediump`/`lowp` are not listed. Does not include
+/// the much longer "reserved for future use" list (`common`, `noinline`, `hvec2`, ...): those
+/// aren't legal identifiers in any shipping compiler either, but they're also exceedingly unlikely
+/// to be picked by accident, and keeping this list to words a real shader would plausibly collide
+/// with keeps it reviewable.
+pub const GLSL_RESERVED_WORDS: &[&str] = &[
+  "attribute",
+  "const",
+  "uniform",
+  "varying",
+  "buffer",
+  "shared",
+  "coherent",
+  "volatile",
+  "restrict",
+  "readonly",
+  "writeonly",
+  "layout",
+  "centroid",
+  "flat",
+  "smooth",
+  "noperspective",
+  "patch",
+  "sample",
+  "invariant",
+  "precise",
+  "break",
+  "continue",
+  "do",
+  "for",
+  "while",
+  "switch",
+  "case",
+  "default",
+  "if",
+  "else",
+  "subroutine",
+  "in",
+  "out",
+  "inout",
+  "void",
+  "true",
+  "false",
+  "discard",
+  "return",
+  "struct",
+  "precision",
+];
+
+/// Why [`validate`] rejected a name, as a human-readable fragment (e.g. "starts with a digit")
+/// suitable for splicing into a larger message.
+fn reason(name: &str) -> Option<&'static str> {
+  if name.is_empty() {
+    Some("is empty")
+  } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+    Some("starts with a digit")
+  } else if name.contains(|c: char| !(c.is_ascii_alphanumeric() || c == '_')) {
+    Some("contains a character that isn't ASCII alphanumeric or `_`")
+  } else if GLSL_RESERVED_WORDS.contains(&name) {
+    Some("is a reserved GLSL keyword")
+  } else {
+    None
+  }
+}
+
+/// Check that `name` is a legal GLSL identifier (and not a reserved keyword on top of that, which
+/// is as far as [`glsl::syntax::Identifier::new`] goes). Returns a human-readable reason on
+/// failure.
+pub fn validate(name: &str) -> Result<(), String> {
+  match reason(name) {
+    Some(why) => Err(format!("`{}` is not a legal GLSL identifier: {}", name, why)),
+    None => Ok(()),
+  }
+}
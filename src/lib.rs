@@ -0,0 +1,2866 @@
+//! # GLSL quasiquoting.
+//!
+//! This crate exports a procedural macro: `glsl!`. It enables quasiquoting by allowing you to
+//! embed GLSL source code directly into rust via the syntax:
+//!
+//! ```
+//! use glsl::syntax::TranslationUnit;
+//! use glsl_quasiquote::glsl;
+//!
+//! let tu: TranslationUnit = glsl!{
+//!   // your GLSL code here
+//!   void main() {
+//!   }
+//! };
+//! ```
+//!
+//! The `glsl!` macro accepts the GLSL code directly. You can then write plain GLSL. Especially,
+//! since version **0.2**, the macro accepts plain GLSL pragmas (both `#version` and `#extension`).
+//!
+//! The `glsl!` procedural macro resolves at compile-time to [`TranslationUnit`],
+//! allowing you to manipulate the GLSL AST directly. Feel free to have a look at the
+//! [`glsl`](https://crates.io/crates/glsl) crate for further information.
+//!
+//! # Getting started
+//!
+//! Add the following to your dependencies in your `Cargo.toml`:
+//!
+//! ```toml
+//! glsl = "7"
+//! glsl-quasiquote = "7"
+//! ```
+//!
+//! You currently need a nightly compiler (one of this crate's own dependencies,
+//! `proc-macro-faithful-display`, relies on the still-unstable `proc_macro_span`). Invoking
+//! `glsl!` itself no longer needs a feature flag of its own: `proc_macro_hygiene`, which older
+//! versions of this crate's docs told you to enable for using a function-like proc macro in
+//! expression position, has been stable on every supported compiler for a long time now, and
+//! declaring it today just produces an "unused feature" warning rather than doing anything.
+//!
+//! Then, depending on which you’re using the 2018 edition or not:
+//!
+//! > *Non-2018 edition*
+//!
+//! ```
+//! extern crate glsl;
+//! #[macro_use] extern crate glsl_quasiquote;
+//! ```
+//!
+//! > *2018 edition*
+//!
+//! ```
+//! use glsl_quasiquote::glsl;
+//! ```
+//!
+//! # Special warnings and considerations
+//!
+//! Because of the nature of the Rust tokenizer, dots (`.`) at the beginning of a token is not part
+//! of the token. For instance, `.3` is reinterpreted as `.` and `3` (two tokens). This will lead
+//! to incorrect parsing if you try to represent the number `0.3` with `.3`. While accepted by
+//! [glsl](https://crates.io/crates/glsl), this is not accepted by this crate. This limitation is
+//! due to how Rust tokenizes input in procedural macro and is very unlikely to change.
+//!
+//! [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+//!
+//! A trailing comma is also tolerated (and silently dropped) at the end of a comma-separated list
+//! that real GLSL doesn't allow one in — a function's parameter list, a struct field's shared-type
+//! declarator list (`float x, y,;`), a call's argument list. This is a quasiquote-only leniency:
+//! it's stripped in this crate's own source-reconstruction step before the `glsl` crate's parser
+//! ever sees it, so the same trailing comma handed to that parser directly is still rejected. It
+//! exists to make building a list by string concatenation easier, since the last element no longer
+//! needs special-casing to avoid a trailing separator:
+//!
+//! ```
+//! use glsl_quasiquote::glsl;
+//!
+//! let _ = glsl! {
+//!   void f(float a, float b,) {
+//!   }
+//! };
+//! ```
+//!
+//! # Interpolation
+//!
+//! `glsl!` and `glsl_expr!` accept `#name` holes, splicing the Rust value bound to `name` into
+//! the generated AST at that position. The splice is typed: `#name` is only accepted where a
+//! `glsl::syntax::Identifier` is legal syntactically, and the generated code requires the
+//! spliced value to implement `Into<Identifier>`, so splicing the wrong kind of Rust value is a
+//! compile error rather than a panic at macro-expansion time.
+//!
+//! `#name` in expression position (an initializer, a function argument, a condition, ...) works
+//! the same way, but against `Into<glsl::syntax::Expr>` instead: splice a `bool`/`i32`/`u32`/
+//! `f32`/`f64` straight in (upstream `Expr` already implements `From` for each of them) to get
+//! the matching `BoolConst`/`IntConst`/`UIntConst`/`FloatConst`/`DoubleConst`, or splice an
+//! already-built `Expr` (e.g. from [`glsl_expr!`]) for anything more complex.
+//!
+//! `glsl!` also accepts a `#[#name]` hole wherever a [`FullySpecifiedType`](glsl::syntax::FullySpecifiedType)
+//! (e.g. a variable declaration or function parameter) may optionally start with a type
+//! qualifier, splicing in the `name`d `Option<glsl::syntax::TypeQualifier>` — `None` drops the
+//! qualifier entirely. This doesn't (yet) cover the *mandatory* qualifier on a `buffer`/`uniform`
+//! block declaration, since that one can't just be omitted.
+//!
+//! `glsl!` also accepts a `#name` hole right after the `#version` keyword, splicing in the
+//! `name`d value's version number — `u16: Into<u16>` trivially, but any type implementing
+//! `Into<u16>` works, letting the version number vary at runtime. The profile (`core`,
+//! `compatibility`, `es`) can't be interpolated the same way, since `PreprocessorVersionProfile`
+//! is a closed set of keywords rather than something a splice can stand in for; write the profile
+//! literally.
+//!
+//! `glsl!` also accepts a `#{#name}` hole wherever a type goes (a variable declaration, a
+//! function's return type, a struct field, ...), splicing in the `name`d
+//! [`TypeSpecifier`](glsl::syntax::TypeSpecifier) value directly rather than re-serializing it
+//! into GLSL source:
+//!
+//! ```
+//! use glsl::syntax::{TypeSpecifier, TypeSpecifierNonArray};
+//! use glsl_quasiquote::glsl;
+//!
+//! let elem = TypeSpecifier::from(TypeSpecifierNonArray::Vec4);
+//!
+//! let tu = glsl! {
+//!   struct Buf {
+//!     #{#elem} data;
+//!   };
+//! };
+//! ```
+//!
+//! `glsl!` also accepts a `#<#name>` hole wherever a [`TypeQualifierSpec`](glsl::syntax::TypeQualifierSpec)
+//! goes (alongside other qualifiers or on its own), splicing in the `name`d value as a
+//! [`PrecisionQualifier`](glsl::syntax::PrecisionQualifier) through `Into<PrecisionQualifier>` —
+//! useful for varying a declaration's precision by target (desktop GL versus GLES) without
+//! re-parsing GLSL source for each:
+//!
+//! ```
+//! use glsl::syntax::PrecisionQualifier;
+//! use glsl_quasiquote::glsl;
+//!
+//! let prec = PrecisionQualifier::Medium;
+//!
+//! let tu = glsl! {
+//!   #<#prec> float foo() {
+//!     return 0.0;
+//!   }
+//! };
+//! ```
+//!
+//! This doesn't (yet) cover the precision qualifier inside a standalone `precision <qual> <type>;`
+//! statement: unlike `subroutine(..)`, the `<qual>` slot there is parsed directly as one of the
+//! three literal keywords `highp`/`mediump`/`lowp` with no identifier or parenthesized form
+//! accepted at all, so there's no legal GLSL token shape a splice placeholder could stand in for
+//! — the same closed-keyword situation a `#version` directive's profile is in (see above). Write
+//! the qualifier literally there, or build the declaration-position qualifier above instead.
+//!
+//! `glsl!` also accepts a `#|#name|` hole wherever a [`TypeQualifierSpec`](glsl::syntax::TypeQualifierSpec)
+//! goes, the same way `#<#name>` does for a precision qualifier, splicing in the `name`d value as a
+//! [`StorageQualifier`](glsl::syntax::StorageQualifier) through `Into<StorageQualifier>` — useful
+//! for a declaration whose direction flips between pipeline stages (`in` in the fragment shader,
+//! `out` in the vertex shader) without duplicating the rest of the declaration for each:
+//!
+//! ```
+//! use glsl::syntax::StorageQualifier;
+//! use glsl_quasiquote::glsl;
+//!
+//! let dir = StorageQualifier::In;
+//!
+//! let tu = glsl! {
+//!   #|#dir| vec3 normal;
+//! };
+//! ```
+//!
+//! `glsl!` also accepts a `#~#name~` hole wherever a
+//! [`FullySpecifiedType`](glsl::syntax::FullySpecifiedType) goes (a variable declaration's type, a
+//! function's return type, ...), splicing in the `name`d value directly through
+//! `Into<FullySpecifiedType>` — unlike `#{#name}`, which only replaces the `TypeSpecifier` and
+//! leaves any qualifier written alongside it alone, this replaces the qualifier too, for a type
+//! that's computed as a whole (e.g. built up generically, qualifier included) rather than having
+//! its qualifier and specifier computed separately:
+//!
+//! ```
+//! use glsl::syntax::{
+//!   FullySpecifiedType, NonEmpty, TypeQualifier, TypeQualifierSpec, TypeSpecifier,
+//!   StorageQualifier,
+//! };
+//!
+//! use glsl_quasiquote::glsl_declaration;
+//!
+//! let ty = FullySpecifiedType {
+//!   qualifier: Some(TypeQualifier {
+//!     qualifiers: NonEmpty(vec![TypeQualifierSpec::Storage(StorageQualifier::Const)]),
+//!   }),
+//!   ty: TypeSpecifier::from(glsl::syntax::TypeSpecifierNonArray::Float),
+//! };
+//!
+//! let decl = glsl_declaration! {
+//!   #~#ty~ foo = 1.0;
+//! };
+//! ```
+//!
+//! `glsl!` additionally accepts a top-level `#(#name)` repetition, splicing in every
+//! `glsl::syntax::ExternalDeclaration` yielded by the `name`d `IntoIterator` in order, ahead of
+//! whatever literal declarations follow it:
+//!
+//! ```
+//! use glsl::syntax::ExternalDeclaration;
+//! use glsl_quasiquote::glsl;
+//!
+//! let uniforms: Vec<ExternalDeclaration> = vec![];
+//!
+//! let tu = glsl! {
+//!   #(#uniforms)
+//!
+//!   void main() {
+//!   }
+//! };
+//! ```
+//!
+//! The splice preserves exactly the order the `IntoIterator` yields, with no reordering or
+//! deduplication of its own — so splicing straight from a `HashMap` (or anything else whose
+//! iteration order isn't a documented guarantee) makes the generated shader's declaration order,
+//! and therefore its token stream, vary from one build to the next. That defeats anything
+//! downstream that hashes or caches the compiled output. Since this crate is `proc-macro = true`
+//! and so can only export `#[proc_macro]` functions (see "Reusing this crate's tokenization from
+//! another proc macro" below), a standalone sorted-splice helper isn't something this crate can
+//! ship; reach instead for whatever already gives a stable order on the caller's side — a
+//! `BTreeMap` keyed on declaration name instead of a `HashMap`, or a `Vec` explicitly
+//! `sort_by_key`'d before it's spliced:
+//!
+//! ```
+//! use std::collections::BTreeMap;
+//! use glsl::syntax::ExternalDeclaration;
+//! use glsl_quasiquote::glsl;
+//!
+//! let uniforms: BTreeMap<&str, ExternalDeclaration> = BTreeMap::new();
+//! let uniforms = uniforms.into_values();
+//!
+//! let tu = glsl! {
+//!   #(#uniforms)
+//!
+//!   void main() {
+//!   }
+//! };
+//! ```
+//!
+//! The same `#(#name)` repetition also works inside any parenthesized argument list, most usefully
+//! a function call's or array constructor's, splicing in every element of the `name`d
+//! `IntoIterator` (converted through [`Into<Expr>`](glsl::syntax::Expr), the same conversion a
+//! plain `#name` hole in expression position uses) as a separate argument, in order, alongside
+//! whatever literal arguments surround it:
+//!
+//! ```
+//! use glsl_quasiquote::glsl;
+//!
+//! let weights: Vec<f32> = vec![0.1, 0.2, 0.7];
+//!
+//! let tu = glsl! {
+//!   const float kernel[3] = float[](#(#weights));
+//! };
+//! ```
+//!
+//! When the array's own size is itself a spliced `#name` hole, the generated code additionally
+//! asserts at runtime that it matches the spliced argument list's length, since neither is known
+//! until the values behind both splices exist:
+//!
+//! ```should_panic
+//! use glsl_quasiquote::glsl;
+//!
+//! let n = 4u32;
+//! let weights: Vec<f32> = vec![0.1, 0.2, 0.7];
+//!
+//! let _ = glsl! {
+//!   const float kernel[#n] = float[](#(#weights));
+//! };
+//! ```
+//!
+//! `##` escapes to a single literal `#`, for the rare case where the body needs a genuine `#`
+//! that isn't one of the forms above — most commonly the C preprocessor's own `#` stringize
+//! operator inside a `#define`'s body (`#define STR(x) #x`), which would otherwise be read as a
+//! `#x` interpolation hole:
+//!
+//! ```
+//! use glsl_quasiquote::glsl;
+//!
+//! let name = "compute_width";
+//!
+//! let _ = glsl! {
+//!   #define STR(x) ##x
+//!
+//!   void #name() {
+//!   }
+//! };
+//! ```
+//!
+//! ## Where a hole can't go
+//!
+//! Every form above works the same two-phase way: [`holes::extract_holes`] replaces it, in the
+//! rendered source text, with a placeholder that's grammatically legal right there (a bare
+//! identifier, a `subroutine(..)` qualifier, ...) *before* the [`glsl`] parser ever runs, and once
+//! parsing hands back an AST, [`tokenize`](crate::tokenize) recognizes the placeholder and emits
+//! the spliced Rust value in its place instead of the placeholder's own (meaningless) text. A hole
+//! only works somewhere a whole placeholder of one of those shapes is itself legal, which rules
+//! out a few positions that might otherwise seem like they should work:
+//!
+//! - As a list of statements spliced into an existing function body — unlike a declaration list,
+//!   a call's argument list, or a struct's field list, there's no `#(#name)` repetition support
+//!   for a `CompoundStatement`'s statement list. [`glsl_function!`] does accept a bare `#name` hole
+//!   standing in for a whole `CompoundStatement` right in the body position, though (build it
+//!   separately with [`glsl_compound!`] and splice the two together), since that's one whole value
+//!   rather than a list being spliced into a larger one.
+//! - As part of a larger token, e.g. `foo#name` to build an identifier by concatenation — a hole
+//!   always stands for one whole placeholder token, never a fragment glued onto surrounding text.
+//!
+//! # `glsl_str!` and `glsl_expr!`
+//!
+//! `glsl!` reconstructs the original line breaks and spacing from the input tokens' spans (see
+//! [`faithful_display`](proc_macro_faithful_display::faithful_display)), so preprocessor pragmas
+//! like `#version 330 core` already work directly inside `glsl!{ .. }`. This crate also exports
+//! [`glsl_str!`], which takes a single Rust string literal and parses its *content* as a
+//! [`TranslationUnit`], for the cases `glsl!` genuinely can't handle: shader source you don't
+//! have as literal tokens in the first place, such as one loaded with `include_str!` or built up
+//! at compile time by another macro. [`glsl_expr!`] is the same idea, restricted to a single
+//! [`Expr`](glsl::syntax::Expr), and accepts either a bare token tree (like [`glsl!`]) or a string
+//! literal.
+//!
+//! # Reusing this crate's tokenization from another proc macro
+//!
+//! This crate is declared `proc-macro = true`, which means the only items it can export are the
+//! `#[proc_macro]` functions themselves — a crate of this kind cannot export ordinary structs,
+//! traits, or functions for another crate to `use`, no matter how `pub` they're marked (that's a
+//! restriction `rustc` enforces on every proc-macro crate, not a choice made here). So a
+//! `ToTokens`-implementing newtype wrapping [`TranslationUnit`]/[`Expr`](glsl::syntax::Expr)/
+//! [`Statement`](glsl::syntax::Statement) for other proc macros to embed in their own `quote!{}`
+//! can't live in this crate; it would need a second, non-proc-macro crate (e.g.
+//! `glsl-quasiquote-tokenize`) that this one depends on for its own `tokenize_*` functions, with
+//! this crate re-exporting nothing but the macros as it does today. That's a bigger, breaking
+//! restructuring than a single change belongs in — tracked as a separate piece of work rather than
+//! attempted here. In particular, a `pub fn quote_expr(e: &Expr) -> proc_macro2::TokenStream`
+//! mirroring `tokenize_expr` for exactly this purpose runs into the same wall: it's an ordinary
+//! function, not a `#[proc_macro]`, so `rustc` rejects exporting it from this crate regardless of
+//! how it's written.
+//!
+//! # Assembling a `TranslationUnit` from individual macros
+//!
+//! For a fully dynamic shader assembled piece by piece instead of written as one `glsl!{}` block,
+//! the same `proc-macro = true` restriction above means this crate can't export a
+//! `TranslationUnitBuilder` type to accumulate declarations into — only the macros themselves can
+//! cross the crate boundary. But nothing about that restriction is specific to a builder: the
+//! pieces one would be built out of are already plain, ordinary [`glsl`] crate types (not ours),
+//! so the same accumulation is just as easy to write directly, with whatever shape (a `Vec`, a
+//! newtype wrapping one, a real builder `struct`) fits the calling crate best:
+//!
+//! ```
+//! use glsl::syntax::{ExternalDeclaration, TranslationUnit};
+//! use glsl_quasiquote::{glsl_declaration, glsl_function};
+//!
+//! let mut decls = Vec::new();
+//!
+//! decls.push(ExternalDeclaration::Declaration(glsl_declaration! {
+//!   uniform float time;
+//! }));
+//!
+//! decls.push(ExternalDeclaration::FunctionDefinition(glsl_function! {
+//!   void main() {
+//!     gl_FragColor = vec4(time);
+//!   }
+//! }));
+//!
+//! let tu = TranslationUnit::from_non_empty_iter(decls).expect("at least one declaration");
+//! ```
+//!
+//! # On generated code size
+//!
+//! Every `tokenize_*` function expands its node as a literal, fully-spelled-out
+//! `::glsl::syntax::Foo { .. }` (or `Foo::Variant(..)`) construction, so a large shader's
+//! generated code is proportional to its AST size. A "compact mode" that instead encoded the AST
+//! to a byte string and decoded it at runtime would need two things this crate can't provide on
+//! its own: a stable binary encoding for `glsl::syntax` types (the upstream [`glsl`] crate derives
+//! no `serde`/binary (de)serialization at all — that's this crate's one dependency, not something
+//! it's in a position to add), and somewhere to put the runtime decoder function, which — being
+//! ordinary code the *generated* code would call at runtime rather than at macro-expansion time —
+//! hits the same `proc-macro = true` export restriction described above: it would have to live in
+//! a new, non-proc-macro runtime crate this one depends on, not inline here. Both are real,
+//! larger pieces of work; this commit doesn't attempt either, to avoid a half-working byte format
+//! with no verified decoder round-tripping it.
+//!
+//! # Nesting depth limit
+//!
+//! Parsing and [`tokenize_expr`](tokenize::tokenize_expr) both recurse once per nesting level of
+//! an expression, so a pathologically (or adversarially) deep one — thousands of parentheses, or
+//! a long unparenthesized chain like `a + a + a + ...` — would otherwise blow the stack during
+//! macro expansion and crash the build with an opaque segfault rather than a readable error. Past
+//! a depth of 256 (raise or lower it by setting `GLSL_QUASIQUOTE_MAX_EXPR_DEPTH` before building),
+//! this crate's macros report a `compile_error!` instead:
+//!
+//! ```compile_fail
+//! use glsl_quasiquote::glsl_expr;
+//!
+//! let _ = glsl_expr! {
+//!   - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - x
+//! };
+//! ```
+//!
+//! # Borrowed identifiers
+//!
+//! Every name in a generated `::glsl::syntax::Identifier` or [`TypeName`](glsl::syntax::TypeName)
+//! is spelled out as an owned `String`, so quoting a shader with many names allocates once per
+//! name at macro-expansion output time — paid at compile time, not worth avoiding here. For a
+//! shader rebuilt at *runtime* from a template (the hot-path case this crate's docs elsewhere warn
+//! about under "On generated code size"), those `Identifier`s are reconstructed every call, and an
+//! owned `String` means a fresh allocation for every name, every time.
+//!
+//! The `borrowed-identifiers` Cargo feature exists for that case, but is currently a no-op: the
+//! `glsl` 7.x [`Identifier`](glsl::syntax::Identifier) this crate depends on is defined as
+//! `Identifier(pub String)`, with no lifetime parameter and no borrowed variant to switch
+//! `tokenize_identifier` over to — there is nothing to gate a `&'static str` code path behind until
+//! a future `glsl` release adds one. Enabling the feature today compiles and changes nothing.
+//! Should `glsl` ever grow a borrowed `Identifier` form, `tokenize_identifier` would switch to
+//! emitting it under this same feature flag rather than introducing a new one.
+//!
+//! # On the `glsl` dependency version
+//!
+//! This crate's own version number tracks the major version of the `glsl` crate it tokenizes
+//! against, and it already depends on `glsl = "7"` — there is no older `glsl = "0.9"` still pinned
+//! here to move off of. `glsl` 7.x's syntax tree is also still the same shape every `tokenize_*`
+//! function already targets: plain enums and structs, with no span-carrying `Node<T>` wrapper
+//! around each node. Gating an old-AST and a new-AST tokenizer behind a feature flag would require
+//! depending on two incompatible major versions of a crate named `glsl` at once, which Cargo
+//! doesn't support without one of them being renamed/vendored under a different package name — a
+//! much larger restructuring than a single change belongs in, and not attempted here. When a future
+//! `glsl` release does introduce a breaking AST shape, this is the place that restructuring would
+//! start.
+
+extern crate proc_macro;
+
+use std::collections::HashSet;
+
+use glsl::parser::Parse;
+use glsl::syntax;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::LitStr;
+
+use crate::tokenize::Tokenize;
+
+mod cache;
+mod comments;
+mod crate_path;
+mod depth;
+mod holes;
+mod identifier;
+mod quoted;
+mod stage;
+mod tokenize;
+
+/// Parse `src` as a [`TranslationUnit`] and tokenize it back into Rust code, or return the parse
+/// error instead of panicking.
+///
+/// This is the fallible core shared by [`glsl!`] and [`glsl_str!`], which both turn its error
+/// into a spanned `compile_error!` (see [`glsl_quote_error_to_compile_error`]). It would be natural to expose this (and
+/// [`GlslQuoteError`]) as a public, `Result`-returning function for `build.rs` scripts and tests
+/// to drive directly, but this crate is `proc-macro = true`, and a `proc-macro` crate cannot
+/// export *any* ordinary item — not even a private-looking `pub fn` — only the
+/// `#[proc_macro]`-tagged macros themselves are legal, so there is no way to hand this out beyond
+/// the macros below.
+///
+/// [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+fn try_quote_str(src: &str) -> Result<TokenStream, GlslQuoteError> {
+  let tu = parse_translation_unit_fully(src)?;
+
+  if cfg!(feature = "check-duplicate-functions") {
+    if let Some(name) = duplicate_function_definition(&tu) {
+      return Err(GlslQuoteError::DuplicateFunctionDefinition(name));
+    }
+  }
+
+  let mut stream = TokenStream::new();
+  tu.tokenize(&mut stream);
+  Ok(stream)
+}
+
+/// Find the name of the first function defined more than once in `tu` with the same parameter
+/// signature, if any — behind the `check-duplicate-functions` feature (see
+/// [`GlslQuoteError::DuplicateFunctionDefinition`]).
+///
+/// Two definitions collide only if both their name and their parameter *types*, in order, match —
+/// comparing parameter names or qualifiers would reject legal overloading by parameter type (e.g.
+/// `float add(float a, float b)` next to `int add(int a, int b)`), which GLSL allows.
+fn duplicate_function_definition(tu: &syntax::TranslationUnit) -> Option<String> {
+  let mut seen = HashSet::new();
+
+  for ed in &tu.0 {
+    let syntax::ExternalDeclaration::FunctionDefinition(def) = ed else {
+      continue;
+    };
+
+    let signature = (
+      def.prototype.name.as_str().to_owned(),
+      def.prototype.parameters.iter().map(|p| format!("{:?}", parameter_type(p))).collect::<Vec<_>>(),
+    );
+
+    if !seen.insert(signature) {
+      return Some(def.prototype.name.as_str().to_owned());
+    }
+  }
+
+  None
+}
+
+/// The parameter type a [`FunctionParameterDeclaration`](syntax::FunctionParameterDeclaration)
+/// carries, whether or not it also names the parameter.
+fn parameter_type(p: &syntax::FunctionParameterDeclaration) -> &syntax::TypeSpecifier {
+  match p {
+    syntax::FunctionParameterDeclaration::Named(_, declarator) => &declarator.ty,
+    syntax::FunctionParameterDeclaration::Unnamed(_, ty) => ty,
+  }
+}
+
+/// The ways the fallible core ([`try_quote_str`]/[`parse_fully`]) can fail to produce a value.
+///
+/// This exists so those functions (and the macros built on them) have a structured cause to
+/// match on instead of a bare message string — but it can't be `pub`: see [`try_quote_str`]'s doc
+/// comment on why nothing ordinary can be exported from this crate, `proc-macro = true` or not.
+/// So despite the shape a request for this might expect, there's still no way for an external
+/// `build.rs` to `match` on it; [`Display`](std::fmt::Display) is the only thing that crosses the
+/// boundary, via the `compile_error!` messages built by [`glsl_quote_error_to_compile_error`].
+enum GlslQuoteError {
+  /// The input didn't parse as the requested GLSL construct at all.
+  Parse(String),
+  /// The input parsed, but left over non-whitespace content afterward.
+  TrailingInput(String),
+  /// The input nests `(`/`{`/`[` deeper than [`depth::max_depth`], which would risk overflowing
+  /// the stack in [`glsl`]'s own recursive-descent parser before it ever got a chance to report a
+  /// genuine syntax error — checked up front instead, on the source text itself, since that
+  /// parser's recursion isn't ours to instrument.
+  TooDeeplyNested { found: usize, max: usize },
+  /// Two function definitions in the same [`TranslationUnit`](syntax::TranslationUnit) share both
+  /// a name and a parameter signature — only checked behind the `check-duplicate-functions`
+  /// feature, since it's a real class of codegen bug (most often hit when splicing
+  /// runtime-generated functions) but too strict to force on everyone: legal overloading by
+  /// parameter type looks identical up to this point.
+  DuplicateFunctionDefinition(String),
+}
+
+impl std::fmt::Display for GlslQuoteError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      GlslQuoteError::Parse(e) => write!(f, "GLSL error: {}", e),
+      GlslQuoteError::TrailingInput(rest) => {
+        write!(f, "GLSL error: unexpected trailing input: {:?}", rest)
+      }
+      GlslQuoteError::TooDeeplyNested { found, max } => write!(
+        f,
+        "GLSL error: nested {} levels deep, exceeding the limit of {} (set GLSL_QUASIQUOTE_MAX_EXPR_DEPTH to raise it)",
+        found, max
+      ),
+      GlslQuoteError::DuplicateFunctionDefinition(name) => write!(
+        f,
+        "GLSL error: `{}` is defined more than once with the same parameter signature",
+        name
+      ),
+    }
+  }
+}
+
+/// Parse `src` as `T`, failing if any non-whitespace input is left over afterward.
+///
+/// [`Parse::parse`] silently discards whatever it didn't consume — `void main() {} junk` parses
+/// just fine as a one-declaration [`TranslationUnit`], with `junk` dropped on the floor — because
+/// the lower-level `nom` parsers and the remaining input they'd report are private to the `glsl`
+/// crate. The only way to recover that boundary from outside is to binary-search for the
+/// shortest prefix of `src` that already parses to the same value `src` in full did: once a
+/// prefix is long enough to account for everything [`Parse::parse`] actually used, every longer
+/// prefix (up to the real trailing garbage) parses to that identical value too.
+///
+/// `src` has already had any holes replaced with placeholder identifiers by
+/// [`holes::extract_holes`] by the time this runs, always the same way for the same hole
+/// structure, so [`cache`] can memoize the result across macro invocations that happen to quote
+/// identical source (e.g. the same prelude shader included from many modules) without risking a
+/// stale splice.
+///
+/// [`GlslQuoteError::Parse`] is rendered via [`Parse::parse`]'s error's `Display` impl rather
+/// than `Debug`: `glsl`'s `ParseError` already carries a `nom`-generated line number and a
+/// one-line source excerpt with a `^` caret pointing at the offending column, and `Display`
+/// preserves that formatting (including its newlines) verbatim, while `Debug` would escape it
+/// into an unreadable single-line string.
+///
+/// [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+fn parse_fully<T>(src: &str) -> Result<T, GlslQuoteError>
+where
+  T: Parse + PartialEq + Clone + 'static,
+{
+  let max = depth::max_depth();
+  let found = depth::max_bracket_depth(src);
+
+  if found > max {
+    return Err(GlslQuoteError::TooDeeplyNested { found, max });
+  }
+
+  cache::get_or_insert_with(src, || {
+    let value: T = Parse::parse(src).map_err(|e| GlslQuoteError::Parse(format!("{}", e)))?;
+
+    if let Some(rest) = trailing_garbage(src, &value) {
+      return Err(GlslQuoteError::TrailingInput(rest.to_owned()));
+    }
+
+    Ok(value)
+  })
+}
+
+/// Find the suffix of `s` left over after parsing it as a `T`, if any, by binary-searching for
+/// the shortest prefix that already parses to `parsed`.
+fn trailing_garbage<'a, T>(s: &'a str, parsed: &T) -> Option<&'a str>
+where
+  T: Parse + PartialEq + 'static,
+{
+  let boundaries = s
+    .char_indices()
+    .map(|(i, _)| i)
+    .chain(std::iter::once(s.len()))
+    .collect::<Vec<_>>();
+
+  let mut lo = 0;
+  let mut hi = boundaries.len() - 1;
+
+  while lo < hi {
+    let mid = lo + (hi - lo) / 2;
+    let candidate: Result<T, _> = Parse::parse(&s[..boundaries[mid]]);
+    let consumed = matches!(&candidate, Ok(value) if value == parsed);
+
+    if consumed {
+      hi = mid;
+    } else {
+      lo = mid + 1;
+    }
+  }
+
+  let rest = s[boundaries[hi]..].trim_start();
+
+  if rest.is_empty() {
+    None
+  } else if rest == ";" && is_global_qualifier_declaration(parsed) {
+    // `glsl`'s `global_declaration` parser (the grammar behind `Declaration::Global`, e.g.
+    // `invariant gl_Position;` or a bare `layout(...) in;`) consumes its type qualifier and
+    // optional identifier list but, unlike every other alternative in `declaration`'s `alt`, never
+    // consumes the statement's own trailing `;` — and `Parse::parse` silently discards whatever a
+    // parser leaves unconsumed, so the shortest matching prefix found above stops one character
+    // short of `s`. Treat that lone leftover `;` as the terminator this variant's own grammar
+    // forgot to eat, rather than reporting it as trailing garbage.
+    None
+  } else {
+    Some(rest)
+  }
+}
+
+/// Whether `parsed` is a [`syntax::Declaration::Global`] — the one `T` [`trailing_garbage`] special-cases,
+/// since it's the only grammar rule reachable through [`parse_fully`] whose own parser doesn't consume
+/// its trailing `;`. `T: 'static` (already required by [`parse_fully`]) makes the downcast possible
+/// without a dedicated trait just for this one quirk.
+fn is_global_qualifier_declaration<T: 'static>(parsed: &T) -> bool {
+  matches!(
+    (parsed as &dyn std::any::Any).downcast_ref::<syntax::Declaration>(),
+    Some(syntax::Declaration::Global(..))
+  )
+}
+
+/// The name of the dummy declaration [`parse_translation_unit_fully`] appends to `src` before
+/// parsing, chosen unlikely enough to collide with a real identifier that it's safe to assume any
+/// occurrence found while stripping it back out is the one we appended.
+const TRANSLATION_UNIT_SENTINEL_NAME: &str = "__glsl_quasiquote_sentinel__";
+
+/// Parse `src` as a [`syntax::TranslationUnit`], working around the same `glsl` grammar quirk as
+/// [`is_global_qualifier_declaration`], but for the case [`parse_fully`]'s single-`;` special case
+/// can't reach: `global_declaration`'s unconsumed trailing `;` doesn't just confuse our own
+/// trailing-garbage check, it confuses `glsl`'s *own* `translation_unit` parser, because
+/// `external_declaration` treats that dangling `;` as a stray statement separator and `cut`s on
+/// whatever (if anything) follows it — so a bare `layout(...) in;`-style declaration at the end of
+/// a shader, with nothing after it to satisfy that `cut`, fails to parse as part of a
+/// [`TranslationUnit`] at all, not merely with leftover trailing input.
+///
+/// Sidestepped by always parsing `src` with a harmless dummy declaration appended, then requiring
+/// that exact declaration be the last item parsed back out before stripping it: reaching it at all
+/// means nothing in `src` blocked the parser from running all the way through `src` and into the
+/// dummy declaration behind it, which is exactly the condition under which `src` on its own would
+/// otherwise have hit the quirk above. Any other outcome (the dummy declaration doesn't show up
+/// last, or appending it errors) falls back to the plain, unpadded parse and today's ordinary
+/// trailing-garbage diagnostic, so real mistakes in `src` keep reporting the way they always have.
+///
+/// [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+fn parse_translation_unit_fully(src: &str) -> Result<syntax::TranslationUnit, GlslQuoteError> {
+  let max = depth::max_depth();
+  let found = depth::max_bracket_depth(src);
+
+  if found > max {
+    return Err(GlslQuoteError::TooDeeplyNested { found, max });
+  }
+
+  cache::get_or_insert_with(src, || {
+    let padded = format!("{}\nvoid {}();", src, TRANSLATION_UNIT_SENTINEL_NAME);
+
+    if let Ok(tu) = Parse::parse(&padded) {
+      if let Some(tu) = strip_translation_unit_sentinel(tu) {
+        return Ok(tu);
+      }
+    }
+
+    let value: syntax::TranslationUnit =
+      Parse::parse(src).map_err(|e| GlslQuoteError::Parse(format!("{}", e)))?;
+
+    if let Some(rest) = trailing_garbage(src, &value) {
+      Err(GlslQuoteError::TrailingInput(rest.to_owned()))
+    } else {
+      Ok(value)
+    }
+  })
+}
+
+/// Remove [`parse_translation_unit_fully`]'s sentinel declaration, if it's the last declaration in
+/// `tu` — and only then, since finding it anywhere else (or not at all) means the source it was
+/// appended to didn't parse all the way through on its own.
+fn strip_translation_unit_sentinel(
+  mut tu: syntax::TranslationUnit,
+) -> Option<syntax::TranslationUnit> {
+  let is_sentinel = matches!(
+    tu.0 .0.last(),
+    Some(syntax::ExternalDeclaration::Declaration(syntax::Declaration::FunctionPrototype(fp)))
+      if fp.name.as_str() == TRANSLATION_UNIT_SENTINEL_NAME
+  );
+
+  if !is_sentinel || tu.0 .0.len() < 2 {
+    return None;
+  }
+
+  tu.0 .0.pop();
+
+  Some(tu)
+}
+
+/// The span to anchor a [`GlslQuoteError`]'s `compile_error!` at: the first token of a macro's raw
+/// input, standing in for "the site of this `glsl!{...}` invocation" the way a bare `panic!` at
+/// macro-expansion time doesn't — a panicking proc macro shows up to `rustc` as an opaque "proc
+/// macro panicked" note with no source snippet, while a `compile_error!` spanned like this prints
+/// the offending invocation the same way any other diagnostic does. Falls back to
+/// [`proc_macro2::Span::call_site`] for a macro invoked with no input at all (`glsl!{}`, already
+/// rejected before this would matter, but harmless either way).
+fn invocation_span(input: &proc_macro::TokenStream) -> proc_macro2::Span {
+  proc_macro2::TokenStream::from(input.clone())
+    .into_iter()
+    .next()
+    .map(|tt| tt.span())
+    .unwrap_or_else(proc_macro2::Span::call_site)
+}
+
+/// Best-effort sharpening of [`invocation_span`]'s "whole invocation" span down to the single
+/// token nearest the line [`GlslQuoteError::Parse`]'s underlying `nom` error reports, so an error
+/// that points at one bad token in a large `glsl!{...}` body lands the caret there in the editor
+/// instead of on the invocation's opening token. `glsl`'s `ParseError` only exposes that line
+/// number embedded in its `Display` text (see `parse_fully`'s doc comment), so it's recovered by
+/// matching nom's own `convert_error` wording (`"at line N:"`) rather than anything structured.
+/// That line number is 1-based *within the reconstructed source* handed to the parser, which
+/// always has exactly as many lines as the real invocation (holes are substituted in place,
+/// without adding or removing any `\n`), so adding it to `fallback`'s own line (minus one) recovers
+/// the real line in the caller's file — then `input`'s tokens (descending into groups, since a
+/// `glsl!{ .. }` body is itself one top-level group by the time `rustc` hands it to us) are walked
+/// for the first one starting on that line. Falls back to `fallback` outright if the error isn't a
+/// `Parse` variant, if line information isn't available at all (the stable toolchain, or running
+/// outside of a real macro expansion — see [`proc_macro2::Span::start`]), or if no token's line
+/// matches closely enough to trust.
+fn error_span(input: &TokenStream, fallback: proc_macro2::Span, e: &GlslQuoteError) -> proc_macro2::Span {
+  let GlslQuoteError::Parse(message) = e else {
+    return fallback;
+  };
+
+  let base_line = fallback.start().line;
+
+  if base_line == 0 {
+    // No real line information available (stable toolchain, or outside of a macro expansion) —
+    // every span reports line 0, so there's nothing to sharpen against.
+    return fallback;
+  }
+
+  let target_line = match nom_error_line(message) {
+    Some(nom_line) => base_line + nom_line - 1,
+    None => return fallback,
+  };
+
+  nearest_token_span(input, target_line).unwrap_or(fallback)
+}
+
+/// Recover the 1-based line number out of a `nom::error::convert_error`-formatted message's first
+/// `"{i}: at line {line}:"` entry, if any.
+fn nom_error_line(message: &str) -> Option<usize> {
+  let after = message.split("at line ").nth(1)?;
+  let digits: String = after.chars().take_while(|c| c.is_ascii_digit()).collect();
+
+  digits.parse().ok()
+}
+
+/// Find the first token in `input` (descending into groups) starting on `target_line`.
+fn nearest_token_span(input: &TokenStream, target_line: usize) -> Option<proc_macro2::Span> {
+  for tt in input.clone() {
+    let span = tt.span();
+
+    if span.start().line == target_line {
+      return Some(span);
+    }
+
+    if let proc_macro2::TokenTree::Group(g) = tt {
+      if let Some(span) = nearest_token_span(&g.stream(), target_line) {
+        return Some(span);
+      }
+    }
+  }
+
+  None
+}
+
+/// Build the `compile_error!(...)` this crate's macros return in place of [`GlslQuoteError`]'s old
+/// `panic!`, spanned at the token [`error_span`] judges nearest the actual parse failure — falling
+/// back to the whole invocation (see [`invocation_span`]) when that can't be determined — so it
+/// reads like any other diagnostic, ideally with the editor's "go to definition"-style navigation
+/// landing right on the offending GLSL token instead of just the macro call.
+///
+/// `src` is the exact string that was handed to [`parse_fully`] — after `@crate(path)` has been
+/// stripped and holes have been replaced by [`holes::extract_holes`]'s placeholder identifiers, so
+/// it can differ from what was actually typed. That gap is exactly what makes a reconstruction bug
+/// (in [`faithful_display`] or in [`holes`]) hard to spot from the parse error alone, so it's
+/// appended to the message, clearly labeled, rather than left for the caller to reproduce by hand.
+fn glsl_quote_error_to_compile_error(
+  e: GlslQuoteError,
+  span: proc_macro2::Span,
+  src: &str,
+  input: &TokenStream,
+) -> TokenStream {
+  let span = error_span(input, span, &e);
+  let message = format!("{}\n\nreconstructed source handed to the parser:\n{}", e, src);
+  quote_spanned! { span => compile_error!(#message); }
+}
+
+/// Create a [`TranslationUnit`].
+///
+/// A leading `@crate(path)` directive, e.g. `glsl!{ @crate(my_glsl) void main() {} }`, redirects
+/// the generated code's `::glsl::` prefix to `path` instead, for crates that rename or re-export
+/// the [`glsl`] dependency. A leading `@` not followed by a well-formed `crate(path)` directive is
+/// a `compile_error!` naming what was expected instead, rather than the un-spanned "proc macro
+/// panicked" `rustc` prints for a `panic!` with no [`Span`](proc_macro2::Span) attached:
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl;
+///
+/// let _ = glsl! {
+///   @foo
+///   void main() {}
+/// };
+/// ```
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl;
+///
+/// let _ = glsl! {
+///   @crate
+///   void main() {}
+/// };
+/// ```
+///
+/// `glsl!{}` (or any input that's only whitespace/comments) is a `compile_error!` rather than a
+/// panic with the parser's confusing "expected ';', got empty input" message: a
+/// [`TranslationUnit`] is a non-empty list of declarations, so there's no valid empty value to
+/// hand back.
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl;
+///
+/// let _ = glsl! {};
+/// ```
+///
+/// A genuine syntax error is a `compile_error!` carrying [`parse_fully`]'s error, which (via
+/// [`Parse::parse`]'s `Display` impl) names the offending line and shows a one-line excerpt with a
+/// `^` caret under the bad token, rather than a bare byte offset — spanned, where the compiler's
+/// own span locations are available (a nightly toolchain, which this crate already requires; see
+/// the crate root docs), at the specific GLSL token nearest the error rather than just the
+/// `glsl!{...}` invocation as a whole (see [`error_span`]), so an editor's "jump to this
+/// diagnostic" lands on the offending line inside the macro body instead of its opening brace.
+/// Falls back to the invocation itself (see [`invocation_span`]) when a nearer token can't be
+/// worked out. Either way `rustc` prints it like any other diagnostic instead of an opaque "proc
+/// macro panicked". The message also carries the exact, labeled reconstructed source string that
+/// was actually handed to the parser (see [`glsl_quote_error_to_compile_error`]) — it can differ
+/// from what was typed, once `@crate(path)` has been stripped and holes replaced by placeholders,
+/// so seeing it verbatim is what makes a reconstruction bug (rather than a genuine typo) obvious
+/// at a glance:
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl;
+///
+/// let _ = glsl! {
+///   void main() {
+///     int x = ;
+///   }
+/// };
+/// ```
+///
+/// A literal identifier that happens to be a reserved GLSL keyword is also a `compile_error!`:
+/// `glsl`'s own parser doesn't reject it (only a digit-led or non-alphanumeric name is a parse
+/// error), so without this check it would silently produce a [`TranslationUnit`] that only fails
+/// once something downstream tries to compile the GLSL for real (see
+/// [`identifier::validate`](crate::identifier::validate) for the full list of rejected words). A
+/// name spliced in through a `#name` hole gets the same check, just at runtime (a real `assert!`,
+/// which still runs in a downstream crate's `--release` build, once the spliced value is known),
+/// since its text isn't known until the splice actually runs:
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl;
+///
+/// let _ = glsl! {
+///   void precision() {}
+/// };
+/// ```
+///
+/// With the `check-duplicate-functions` feature enabled, two function definitions sharing both a
+/// name and a parameter signature are also a `compile_error!` — see
+/// [`GlslQuoteError::DuplicateFunctionDefinition`].
+///
+/// A `//` or `/* */` comment in the input is cleanly dropped, same as in any other GLSL source. A
+/// `///`/`//!` doc comment is too, as long as it sits directly inside the macro's own `{ }` rather
+/// than nested inside a block — `rustc` lowers one of those to a real attribute that this crate
+/// can't safely remove once it's nested without corrupting the reconstructed source, so that case
+/// is a `compile_error!` instead (see [`comments`](crate::comments)'s module doc for why):
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl;
+///
+/// let _ = glsl! {
+///   void main() {
+///     /// not supported here
+///     int x = 1;
+///   }
+/// };
+/// ```
+///
+/// [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+#[proc_macro]
+pub fn glsl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  if s.trim().is_empty() {
+    return quote! { compile_error!("glsl! requires at least one declaration") }.into();
+  }
+
+  match try_quote_str(&s) {
+    Ok(stream) => crate_path::rewrite(stream, &path).into(),
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Like [`glsl!`], but with the `glsl-debug` feature enabled on this crate, also `eprintln!`s the
+/// parsed [`TranslationUnit`] pretty-printed back to GLSL at runtime, by delegating to the
+/// [`glsl`] crate's own [`transpiler::glsl`](glsl::transpiler::glsl) module (the same one its
+/// `Display` impls are missing, which is the point: this crate reconstructs GLSL source from Rust
+/// token spans, not the other way around, so "what did the macro actually see" is otherwise only
+/// visible by staring at a `Debug`-formatted AST). With the feature off (the default), this is
+/// exactly [`glsl!`] — the eprintln! is gated out entirely at this crate's own compile time via
+/// `cfg!`, not at runtime, so it costs nothing and prints nothing in a normal build:
+///
+/// ```toml
+/// glsl-quasiquote = { version = "7", features = ["glsl-debug"] }
+/// ```
+///
+/// There's no way for this `proc-macro = true` crate to hand out a plain `pub fn` callable at
+/// runtime (see [`try_quote_str`]'s doc comment) to pretty-print a [`TranslationUnit`] you already
+/// have lying around — but you don't need one from here: `glsl::transpiler::glsl::show_translation_unit`
+/// is already `pub` in the [`glsl`] crate itself, which anything using this macro already depends
+/// on directly.
+///
+/// Unlike [`glsl!`], `glsl_debug!` doesn't accept a leading `@crate(path)` directive: the debug
+/// `eprintln!` always reaches the transpiler through the real `::glsl` crate path, so it wouldn't
+/// be correct for a caller that renames or re-exports the dependency.
+///
+/// ```ignore
+/// let _ = glsl_debug! {
+///   void main() {}
+/// };
+/// ```
+///
+/// [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+#[proc_macro]
+pub fn glsl_debug(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let s = match comments::render(input.clone().into()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  if s.trim().is_empty() {
+    return quote! { compile_error!("glsl_debug! requires at least one declaration") }.into();
+  }
+
+  let built = match try_quote_str(&s) {
+    Ok(stream) => crate_path::rewrite(stream, &quote! { glsl }),
+    Err(e) => return glsl_quote_error_to_compile_error(e, span, &s, &input.into()).into(),
+  };
+
+  let stream = if cfg!(feature = "glsl-debug") {
+    quote! {
+      {
+        let __glsl_quasiquote_tu = #built;
+        let mut __glsl_quasiquote_debug_out = ::std::string::String::new();
+
+        ::glsl::transpiler::glsl::show_translation_unit(&mut __glsl_quasiquote_debug_out, &__glsl_quasiquote_tu);
+        ::std::eprintln!("{}", __glsl_quasiquote_debug_out);
+
+        __glsl_quasiquote_tu
+      }
+    }
+  } else {
+    built
+  };
+
+  stream.into()
+}
+
+/// Like [`glsl!`], but instead of the [`TranslationUnit`] itself, expands to a string literal
+/// holding the generated Rust [`TokenStream`](proc_macro2::TokenStream)'s `to_string()` — the
+/// exact code [`glsl!`] would produce for the same input, without reaching for `cargo expand`.
+/// Meant for diagnosing this crate itself (a `@crate(path)` rewrite that isn't reaching where you
+/// expect, a splice landing in the wrong position, ...), not for anything a caller would ship: the
+/// string is [`quote`]'s compact, not-necessarily-`rustfmt`-clean rendering, read by a human
+/// debugging the macro rather than by any downstream code.
+///
+/// ```
+/// use glsl_quasiquote::glsl_tokens_str;
+///
+/// let tokens: &str = glsl_tokens_str! {
+///   void main() {}
+/// };
+///
+/// assert!(tokens.contains("TranslationUnit"));
+/// ```
+///
+/// Accepts the same leading `@crate(path)` directive and `#name` holes as [`glsl!`]; a hole's
+/// spliced value is rendered as whatever identifier it was written as at the call site, the same
+/// as it'd appear in the real expansion.
+///
+/// [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+#[proc_macro]
+pub fn glsl_tokens_str(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  if s.trim().is_empty() {
+    return quote! { compile_error!("glsl_tokens_str! requires at least one declaration") }.into();
+  }
+
+  match try_quote_str(&s) {
+    Ok(stream) => {
+      let text = crate_path::rewrite(stream, &path).to_string();
+      quote! { #text }.into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Like [`glsl!`], but expands to a [`once_cell::sync::Lazy`] initializer instead of a bare
+/// [`TranslationUnit`] expression, so a `static` built from it only pays for parsing and the
+/// resulting `Vec`/`String` construction once, at first access, rather than re-running that
+/// construction as an inline literal every time the static is read:
+///
+/// ```
+/// use glsl_quasiquote::glsl_lazy;
+///
+/// static SHADER: once_cell::sync::Lazy<glsl::syntax::TranslationUnit> = glsl_lazy! {
+///   void main() {}
+/// };
+///
+/// assert_eq!(SHADER.0 .0.len(), 1);
+/// ```
+///
+/// Behind the `glsl-lazy` feature, which pulls in the [`once_cell`] dependency:
+///
+/// ```toml
+/// glsl-quasiquote = { version = "7", features = ["glsl-lazy"] }
+/// once_cell = "1"
+/// ```
+///
+/// [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+#[cfg(feature = "glsl-lazy")]
+#[proc_macro]
+pub fn glsl_lazy(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  if s.trim().is_empty() {
+    return quote! { compile_error!("glsl_lazy! requires at least one declaration") }.into();
+  }
+
+  match try_quote_str(&s) {
+    Ok(stream) => {
+      let stream = crate_path::rewrite(stream, &path);
+      quote! { ::once_cell::sync::Lazy::new(|| #stream) }.into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a [`TranslationUnit`] with every top-level [`Preprocessor`](glsl::syntax::Preprocessor)
+/// declaration (`#version`, `#extension`, `#define`, ...) filtered out, keeping only real code.
+///
+/// Intended for callers that hash or deduplicate shader bodies and want that comparison to be
+/// independent of a `#version`/`#extension` header that can legitimately differ between two
+/// otherwise-identical shaders. Equivalent to [`glsl!`] followed by stripping every
+/// [`ExternalDeclaration::Preprocessor`](glsl::syntax::ExternalDeclaration::Preprocessor) from the
+/// resulting unit, except the filtering happens before tokenizing, so the generated code never
+/// constructs the preprocessor nodes in the first place.
+///
+/// `glsl_clean!{}`, and an input that's nothing but preprocessor directives, are both a
+/// `compile_error!` for the same reason [`glsl!`] rejects an empty invocation: there's no non-empty
+/// [`TranslationUnit`] left to hand back.
+///
+/// ```
+/// use glsl_quasiquote::glsl_clean;
+///
+/// let _ = glsl_clean! {
+///   #version 450
+///
+///   void main() {}
+/// };
+/// ```
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl_clean;
+///
+/// let _ = glsl_clean! {
+///   #version 450
+/// };
+/// ```
+///
+/// [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+#[proc_macro]
+pub fn glsl_clean(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  if s.trim().is_empty() {
+    return quote! { compile_error!("glsl_clean! requires at least one declaration") }.into();
+  }
+
+  let mut tu = match parse_translation_unit_fully(&s) {
+    Ok(tu) => tu,
+    Err(e) => return glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  };
+
+  tu.0 .0.retain(|ed| !matches!(ed, syntax::ExternalDeclaration::Preprocessor(_)));
+
+  if tu.0 .0.is_empty() {
+    return quote! {
+      compile_error!("glsl_clean! input contained only preprocessor directives, leaving no declarations")
+    }
+    .into();
+  }
+
+  let mut stream = TokenStream::new();
+  tu.tokenize(&mut stream);
+
+  crate_path::rewrite(stream, &path).into()
+}
+
+/// Create a [`TranslationUnit`] from one or more adjacent Rust string literals, an
+/// `include_str!(...)` call, or a `concat!(...)` of either.
+///
+/// Use this instead of [`glsl!`] when you don't have the shader source as literal tokens to begin
+/// with, e.g. it was loaded with `include_str!` or assembled with `concat!`. Preprocessor pragmas
+/// like `#version`/`#extension` don't need this: [`glsl!`] already reconstructs the line breaks
+/// they rely on. Accepts the same leading `@crate(path)` directive as [`glsl!`].
+///
+/// Like a plain Rust expression, adjacent string literals are concatenated before parsing, so a
+/// shader assembled from several pieces doesn't need to be joined by hand first:
+///
+/// ```
+/// use glsl_quasiquote::glsl_str;
+///
+/// let _ = glsl_str! {
+///   "void main() {"
+///   "}"
+/// };
+/// ```
+///
+/// `include_str!("path")` and `concat!(...)` calls are also recognized and evaluated directly —
+/// rather than, as any other proc macro would see them, arriving as an unexpanded `include_str`/
+/// `concat` macro call that can't be forced to expand from inside another macro — covering the
+/// common case of keeping a literal prelude (e.g. a `#version` line) in Rust and the shader body
+/// in a file:
+///
+/// ```ignore
+/// let _ = glsl_str! {
+///   concat!("#version 450 core\n", include_str!("body.glsl"))
+/// };
+/// ```
+///
+/// `include_str!`'s path is resolved the same way the real `include_str!` resolves it: relative
+/// to the file containing the call, via [`proc_macro::Span::local_file`] (stable since Rust
+/// 1.88). Nesting a `concat!` inside another `concat!`, or passing it anything other than a
+/// string literal or an `include_str!` call, isn't supported — only the shape above is.
+///
+/// Passing anything else is a `compile_error!` that names what was received instead, rather than
+/// `syn`'s generic "expected string literal" message:
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl_str;
+///
+/// let _ = glsl_str!{ foo };
+/// ```
+///
+/// The whole (concatenated) string has to parse as a [`TranslationUnit`]: trailing content that
+/// doesn't form a further declaration — rather than being silently dropped, as a bare
+/// [`glsl::parser::Parse`] call would — is also a compile-time error:
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl_str;
+///
+/// let _ = glsl_str!{ "void main() {} this is not glsl" };
+/// ```
+///
+/// [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+#[proc_macro]
+pub fn glsl_str(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let input: proc_macro::TokenStream = input.into();
+
+  let s = match concat_str_literals(input.clone()) {
+    Ok(s) => s,
+    Err(None) => {
+      let message = format!(
+        "glsl_str! expects one or more string literals, include_str!(...), or concat!(...), got {}",
+        describe_tokens(&input)
+      );
+      return quote! { compile_error!(#message); }.into();
+    }
+    Err(Some(message)) => {
+      return quote! { compile_error!(#message); }.into();
+    }
+  };
+
+  let s = normalize_line_endings(&s);
+
+  match try_quote_str(&s) {
+    Ok(stream) => crate_path::rewrite(stream, &path).into(),
+    // `input` here is a string literal (or `include_str!`/`concat!` call), not the GLSL token
+    // stream itself, so there's no per-token line to sharpen the span against the way the other
+    // macros do — `error_span` just won't find a matching line and falls back to `span` outright.
+    Err(e) => {
+      glsl_quote_error_to_compile_error(e, span, &s, &proc_macro2::TokenStream::from(input)).into()
+    }
+  }
+}
+
+/// A non-empty sequence of adjacent string literals, concatenated the same way Rust concatenates
+/// adjacent string literals in an ordinary expression.
+struct StrLits(Vec<LitStr>);
+
+impl syn::parse::Parse for StrLits {
+  fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+    let mut lits = Vec::new();
+
+    while !input.is_empty() {
+      lits.push(input.parse()?);
+    }
+
+    if lits.is_empty() {
+      return Err(input.error("expected at least one string literal"));
+    }
+
+    Ok(StrLits(lits))
+  }
+}
+
+/// Parse `input` as one or more adjacent string literals, a single `include_str!(...)` call, or a
+/// single `concat!(...)` call of further literals/`include_str!` calls, and concatenate the
+/// result. `Err(None)` means `input` wasn't recognized as any of those at all; `Err(Some(_))`
+/// means it was recognized but is invalid in some more specific way (e.g. a file that couldn't be
+/// read), worth reporting instead of the generic "expected a string literal" message.
+fn concat_str_literals(input: proc_macro::TokenStream) -> Result<String, Option<String>> {
+  if let Ok(StrLits(lits)) = syn::parse(input.clone()) {
+    return Ok(lits.iter().map(LitStr::value).collect());
+  }
+
+  eval_macro_str_expr(input)
+}
+
+/// Rewrite every `\r\n` and lone `\r` in `s` to a plain `\n`.
+///
+/// Unlike [`glsl!`], which reads its GLSL straight off already-tokenized Rust source (where line
+/// endings are already whatever `rustc` normalized them to), [`glsl_str!`]'s input is an ordinary
+/// string literal's *value* — free to contain whatever bytes its author's string literal encoded,
+/// including a `\r\n` pasted in from a file authored on Windows. `glsl`'s preprocessor-pragma
+/// parsing (`#version`/`#extension`) is sensitive to exactly where a `\n` falls, so a stray `\r`
+/// sitting in front of one can make an otherwise-valid shader fail to parse here alone.
+fn normalize_line_endings(s: &str) -> String {
+  s.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// If `input` is a single `include_str!(...)` or `concat!(...)` call, evaluate it to the string
+/// it would produce (see [`concat_str_literals`]).
+fn eval_macro_str_expr(input: proc_macro::TokenStream) -> Result<String, Option<String>> {
+  let mut tokens = input.clone().into_iter().peekable();
+
+  let name = match tokens.next() {
+    Some(proc_macro::TokenTree::Ident(i)) => i,
+    _ => return Err(None),
+  };
+
+  match tokens.next() {
+    Some(proc_macro::TokenTree::Punct(p)) if p.as_char() == '!' => {}
+    _ => return Err(None),
+  }
+
+  let args = match tokens.next() {
+    Some(proc_macro::TokenTree::Group(g)) if g.delimiter() == proc_macro::Delimiter::Parenthesis => {
+      g.stream()
+    }
+    _ => return Err(None),
+  };
+
+  if tokens.next().is_some() {
+    return Err(None);
+  }
+
+  match name.to_string().as_str() {
+    "include_str" => eval_literal_or_include_str(input).map_err(Some),
+    "concat" => eval_concat(args).map_err(Some),
+    _ => Err(None),
+  }
+}
+
+/// Evaluate a single `concat!` argument: a plain string literal, or an `include_str!(...)` call.
+fn eval_literal_or_include_str(piece: proc_macro::TokenStream) -> Result<String, String> {
+  let mut tokens = piece.into_iter().peekable();
+
+  match tokens.next() {
+    Some(proc_macro::TokenTree::Literal(lit)) => {
+      if tokens.peek().is_some() {
+        return Err("expected a single string literal".to_owned());
+      }
+
+      syn::parse::<LitStr>(proc_macro::TokenStream::from(proc_macro::TokenTree::Literal(lit)))
+        .map(|l| l.value())
+        .map_err(|_| "expected a string literal".to_owned())
+    }
+
+    Some(proc_macro::TokenTree::Ident(ref i)) if i.to_string() == "include_str" => {
+      match tokens.next() {
+        Some(proc_macro::TokenTree::Punct(p)) if p.as_char() == '!' => {}
+        _ => return Err("expected `!` after `include_str`".to_owned()),
+      }
+
+      let args = match tokens.next() {
+        Some(proc_macro::TokenTree::Group(g)) if g.delimiter() == proc_macro::Delimiter::Parenthesis => {
+          g.stream()
+        }
+        _ => return Err("include_str! expects a parenthesized path".to_owned()),
+      };
+
+      if tokens.next().is_some() {
+        return Err("unexpected tokens after include_str!(...)".to_owned());
+      }
+
+      let mut args = args.into_iter();
+
+      let path_lit = match args.next() {
+        Some(proc_macro::TokenTree::Literal(lit)) => lit,
+        _ => return Err("include_str! expects a single string literal path".to_owned()),
+      };
+
+      if args.next().is_some() {
+        return Err("include_str! expects a single string literal path".to_owned());
+      }
+
+      let span = path_lit.span();
+      let path = syn::parse::<LitStr>(proc_macro::TokenStream::from(proc_macro::TokenTree::Literal(path_lit)))
+        .map_err(|_| "include_str! expects a string literal path".to_owned())?;
+
+      read_include_str(&path.value(), span)
+    }
+
+    _ => Err("expected a string literal or an include_str!(...) call".to_owned()),
+  }
+}
+
+/// Evaluate `concat!`'s arguments (already unwrapped from their parentheses), splitting on
+/// top-level commas and evaluating each piece via [`eval_literal_or_include_str`].
+fn eval_concat(args: proc_macro::TokenStream) -> Result<String, String> {
+  let pieces = split_top_level_commas(args);
+
+  if pieces.is_empty() {
+    return Err("concat! expects at least one argument".to_owned());
+  }
+
+  let mut out = String::new();
+
+  for piece in pieces {
+    out.push_str(&eval_literal_or_include_str(piece)?);
+  }
+
+  Ok(out)
+}
+
+/// Split `input` into the token streams between its top-level `,` punctuation, dropping a trailing
+/// empty piece left behind by a trailing comma. Nested groups (e.g. `include_str!(...)`'s own
+/// parentheses) are never split into, since a `Group` is a single token in the outer stream.
+fn split_top_level_commas(input: proc_macro::TokenStream) -> Vec<proc_macro::TokenStream> {
+  let mut pieces = Vec::new();
+  let mut current = Vec::new();
+
+  for tt in input {
+    if let proc_macro::TokenTree::Punct(ref p) = tt {
+      if p.as_char() == ',' {
+        pieces.push(current.drain(..).collect());
+        continue;
+      }
+    }
+
+    current.push(tt);
+  }
+
+  if !current.is_empty() {
+    pieces.push(current.into_iter().collect());
+  }
+
+  pieces
+}
+
+/// Parse a single `env!("VAR")` call, as accepted by [`glsl_include_str!`]'s env-var-rooted path
+/// form, returning `VAR`.
+fn parse_env_call(piece: proc_macro::TokenStream) -> Result<String, String> {
+  let mut tokens = piece.into_iter();
+
+  match tokens.next() {
+    Some(proc_macro::TokenTree::Ident(ref i)) if i.to_string() == "env" => {}
+    _ => return Err("expected `env!(\"VAR\")`".to_owned()),
+  }
+
+  match tokens.next() {
+    Some(proc_macro::TokenTree::Punct(p)) if p.as_char() == '!' => {}
+    _ => return Err("expected `!` after `env`".to_owned()),
+  }
+
+  let args = match tokens.next() {
+    Some(proc_macro::TokenTree::Group(g)) if g.delimiter() == proc_macro::Delimiter::Parenthesis => g.stream(),
+    _ => return Err("env! expects a parenthesized var name".to_owned()),
+  };
+
+  if tokens.next().is_some() {
+    return Err("unexpected tokens after env!(...)".to_owned());
+  }
+
+  let mut args = args.into_iter();
+
+  let var_lit = match args.next() {
+    Some(proc_macro::TokenTree::Literal(lit)) => lit,
+    _ => return Err("env! expects a single string literal var name".to_owned()),
+  };
+
+  if args.next().is_some() {
+    return Err("env! expects a single string literal var name".to_owned());
+  }
+
+  syn::parse::<LitStr>(proc_macro::TokenStream::from(proc_macro::TokenTree::Literal(var_lit)))
+    .map(|l| l.value())
+    .map_err(|_| "env! expects a string literal var name".to_owned())
+}
+
+/// Resolve `path` the same way the real `include_str!` would (relative to the file containing the
+/// call, if not absolute) and read its contents. `span`'s [`local_file`](proc_macro::Span::local_file)
+/// (stable since Rust 1.88) is the only way a proc macro can learn where it was invoked from.
+fn read_include_str(path: &str, span: proc_macro::Span) -> Result<String, String> {
+  let path = std::path::Path::new(path);
+
+  let resolved = if path.is_absolute() {
+    path.to_path_buf()
+  } else {
+    let base = span.local_file().ok_or_else(|| {
+      "include_str! needs the call site's source file path, which this compiler/build \
+       environment doesn't expose"
+        .to_owned()
+    })?;
+
+    base
+      .parent()
+      .unwrap_or_else(|| std::path::Path::new("."))
+      .join(path)
+  };
+
+  std::fs::read_to_string(&resolved)
+    .map_err(|e| format!("couldn't read `{}`: {}", resolved.display(), e))
+}
+
+/// Describe, in human terms, what `input` actually contains, for [`glsl_str!`]'s diagnostic when
+/// it isn't a single string literal.
+fn describe_tokens(input: &proc_macro::TokenStream) -> String {
+  let mut tokens = input.clone().into_iter();
+
+  match (tokens.next(), tokens.next()) {
+    (None, _) => "nothing".to_owned(),
+    (Some(only), None) => describe_token(&only),
+    (Some(first), Some(_)) => format!("multiple tokens, starting with {}", describe_token(&first)),
+  }
+}
+
+/// Describe a single token tree in human terms.
+fn describe_token(tt: &proc_macro::TokenTree) -> String {
+  match tt {
+    proc_macro::TokenTree::Ident(i) => format!("an identifier (`{}`)", i),
+    proc_macro::TokenTree::Group(g) => format!("a `{:?}`-delimited group", g.delimiter()),
+    proc_macro::TokenTree::Punct(p) => format!("the punctuation `{}`", p.as_char()),
+    proc_macro::TokenTree::Literal(l) => format!("the non-string literal `{}`", l),
+  }
+}
+
+/// Create a [`TranslationUnit`] by reading a single file at compile time, the simplest path from
+/// "I have a `.frag`/`.vert` file" to a parsed AST, without spelling out
+/// `glsl_str!{ include_str!("...") }` by hand.
+///
+/// ```ignore
+/// let tu = glsl_include_str!("shaders/post.frag");
+/// ```
+///
+/// The path is resolved the same way `include_str!`'s is: relative to the file containing the
+/// call, via [`proc_macro::Span::local_file`] (stable since Rust 1.88). An actual
+/// `include_str!(...)` of the same path is also emitted (its value discarded) alongside the
+/// parsed result, purely so rustc registers the file as a dependency of the build the same way it
+/// would for a real `include_str!` — the read this macro does itself, at macro-expansion time to
+/// get the content to parse, isn't otherwise visible to the compiler's dependency tracking, so
+/// editing the file without this wouldn't trigger a recompile.
+///
+/// A parse failure names the offending path, not just the line inside it, since that's the one
+/// piece of context [`parse_fully`]'s own error has no way to know. This can't be shown as a
+/// runnable doctest: [`proc_macro::Span::local_file`] resolves relative to rustdoc's synthesized
+/// temporary source file, not this crate's `tests/` directory.
+///
+/// ```ignore
+/// let _ = glsl_quasiquote::glsl_include_str!("tests/fixtures/invalid.glsl");
+/// ```
+///
+/// For an out-of-tree shader directory (e.g. one a build script generates into `OUT_DIR`, or
+/// points at through a build-pipeline-specific variable), a path rooted at an environment
+/// variable is also accepted as a second form, `env!("VAR"), "relative/path"`:
+///
+/// ```ignore
+/// let tu = glsl_include_str!(env!("OUT_DIR"), "pbr.frag");
+/// ```
+///
+/// `VAR` is resolved with [`std::env::var`] at macro-expansion time, the same variable `env!`
+/// itself would read, and a missing variable is a clear `compile_error!` naming it rather than a
+/// panic. The dependency-tracking `include_str!(...)` emitted alongside the parsed result is, in
+/// this form, wrapped in the real `env!`/`concat!` the same way you'd write it by hand
+/// (`include_str!(concat!(env!("VAR"), "/relative/path"))`), so rustc's own dependency tracking
+/// for both the variable and the file is exactly what a hand-written `include_str!` would get.
+///
+/// As with [`glsl_str!`], the file's content is not passed through [`holes::extract_holes`] — a
+/// file read from disk has no Rust splice binding in scope to satisfy a `#name` hole, the same
+/// reason `glsl_str!` itself doesn't support holes either.
+///
+/// Accepts the same leading `@crate(path)` directive as [`glsl!`].
+///
+/// [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+#[proc_macro]
+pub fn glsl_include_str(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let input: proc_macro::TokenStream = input.into();
+  let pieces = split_top_level_commas(input.clone());
+
+  let (file_path, span, dep_include) = match pieces.as_slice() {
+    [piece] => {
+      let mut tokens = piece.clone().into_iter();
+
+      let lit = match (tokens.next(), tokens.next()) {
+        (Some(proc_macro::TokenTree::Literal(lit)), None) => lit,
+        _ => {
+          let message = format!(
+            "glsl_include_str! expects a single string literal path, or `env!(\"VAR\"), \"path\"`, got {}",
+            describe_tokens(&input)
+          );
+          return quote! { compile_error!(#message); }.into();
+        }
+      };
+
+      let span = lit.span();
+      let file_path =
+        match syn::parse::<LitStr>(proc_macro::TokenStream::from(proc_macro::TokenTree::Literal(lit))) {
+          Ok(lit) => lit.value(),
+          Err(_) => {
+            return quote! { compile_error!("glsl_include_str! expects a string literal path"); }.into();
+          }
+        };
+
+      let include = LitStr::new(&file_path, proc_macro2::Span::call_site());
+      let dep_include = quote! { include_str!(#include) };
+
+      (file_path, span, dep_include)
+    }
+
+    [env_piece, rel_piece] => {
+      let var_name = match parse_env_call(env_piece.clone()) {
+        Ok(var_name) => var_name,
+        Err(message) => {
+          let message = format!("glsl_include_str!: {}", message);
+          return quote! { compile_error!(#message); }.into();
+        }
+      };
+
+      let mut tokens = rel_piece.clone().into_iter();
+
+      let rel_lit = match (tokens.next(), tokens.next()) {
+        (Some(proc_macro::TokenTree::Literal(lit)), None) => lit,
+        _ => {
+          return quote! {
+            compile_error!("glsl_include_str!: expected a string literal path after `env!(\"VAR\"), `");
+          }
+          .into();
+        }
+      };
+
+      let span = rel_lit.span();
+      let rel_path =
+        match syn::parse::<LitStr>(proc_macro::TokenStream::from(proc_macro::TokenTree::Literal(rel_lit))) {
+          Ok(lit) => lit.value(),
+          Err(_) => {
+            return quote! { compile_error!("glsl_include_str! expects a string literal path"); }.into();
+          }
+        };
+
+      let var_value = match std::env::var(&var_name) {
+        Ok(value) => value,
+        Err(_) => {
+          let message = format!("glsl_include_str!: environment variable `{}` is not set", var_name);
+          return quote! { compile_error!(#message); }.into();
+        }
+      };
+
+      let file_path = format!("{}/{}", var_value.trim_end_matches('/'), rel_path);
+
+      let var_name_lit = LitStr::new(&var_name, proc_macro2::Span::call_site());
+      let rel_path_lit = LitStr::new(&rel_path, proc_macro2::Span::call_site());
+      let dep_include = quote! { include_str!(concat!(env!(#var_name_lit), "/", #rel_path_lit)) };
+
+      (file_path, span, dep_include)
+    }
+
+    _ => {
+      let message = format!(
+        "glsl_include_str! expects a single string literal path, or `env!(\"VAR\"), \"path\"`, got {}",
+        describe_tokens(&input)
+      );
+      return quote! { compile_error!(#message); }.into();
+    }
+  };
+
+  let s = match read_include_str(&file_path, span) {
+    Ok(s) => s,
+    Err(message) => return quote! { compile_error!(#message); }.into(),
+  };
+
+  let built = match try_quote_str(&s) {
+    Ok(stream) => stream,
+    Err(e) => {
+      let message = format!("{} (while parsing `{}`)", e, file_path);
+      let path_span = proc_macro2::Span::from(span);
+      return quote_spanned! { path_span => compile_error!(#message); }.into();
+    }
+  };
+
+  let stream = quote! {
+    {
+      let _: &str = #dep_include;
+      #built
+    }
+  };
+
+  crate_path::rewrite(stream, &path).into()
+}
+
+/// Create a single [`Expr`](glsl::syntax::Expr).
+///
+/// Accepts the same leading `@crate(path)` directive as [`glsl!`].
+#[proc_macro]
+pub fn glsl_expr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  // A single string literal is parsed by its content, as `glsl_str!` does; anything else is the
+  // GLSL expression's own tokens, holes included.
+  let s = match syn::parse2::<LitStr>(input.clone()) {
+    Ok(lit) => lit.value(),
+    Err(_) => {
+      let s = match comments::render(input.clone()) {
+        Ok(s) => s,
+        Err(err) => return err,
+      };
+      holes::extract_holes(&s)
+    }
+  };
+
+  match parse_fully::<syntax::Expr>(&s) {
+    Ok(expr) => {
+      let mut stream = TokenStream::new();
+      expr.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a `Vec<`[`ExternalDeclaration`](glsl::syntax::ExternalDeclaration)`>` rather than a
+/// whole [`TranslationUnit`].
+///
+/// Takes the exact same input as [`glsl!`] — including `#name`/`#[#name]`/`#(#name)` holes and
+/// the leading `@crate(path)` directive — but hands back the bare, concretely-typed `Vec` instead
+/// of wrapping it in a [`TranslationUnit`], so you can push more runtime-generated declarations
+/// onto it before handing it off, e.g. to a transpiler:
+///
+/// ```
+/// use glsl::syntax::ExternalDeclaration;
+/// use glsl_quasiquote::glsl_decls;
+///
+/// let mut decls: Vec<ExternalDeclaration> = glsl_decls! {
+///   void main() {
+///   }
+/// };
+///
+/// decls.push(glsl_decls! { void extra() {} }.remove(0));
+/// ```
+///
+/// [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+#[proc_macro]
+pub fn glsl_decls(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  if s.trim().is_empty() {
+    return quote! { compile_error!("glsl_decls! requires at least one declaration") }.into();
+  }
+
+  match parse_translation_unit_fully(&s) {
+    Ok(tu) => {
+      let stream = tokenize::tokenize_decls_vec(&tu);
+      crate_path::rewrite(stream, &path).into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single [`CompoundStatement`](glsl::syntax::CompoundStatement), i.e. a brace-delimited
+/// statement list, for assembling a [`FunctionDefinition`](glsl::syntax::FunctionDefinition)'s
+/// body by hand.
+///
+/// The outer braces are mandatory: `glsl_compound!{ { float x = 1.0; return x; } }`, matching
+/// what a [`CompoundStatement`](glsl::syntax::CompoundStatement) actually looks like as GLSL,
+/// rather than the macro silently adding a pair on your behalf. Accepts the same leading
+/// `@crate(path)` directive as [`glsl!`].
+///
+/// ```
+/// use glsl::syntax::CompoundStatement;
+/// use glsl_quasiquote::glsl_compound;
+///
+/// let _: CompoundStatement = glsl_compound! {
+///   {
+///     float x = 1.0;
+///     return x;
+///   }
+/// };
+/// ```
+///
+/// Content that isn't a statement list in braces is a `compile_error!`:
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl_compound;
+///
+/// let _ = glsl_compound! { float x = 1.0; };
+/// ```
+#[proc_macro]
+pub fn glsl_compound(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  match parse_fully::<syntax::CompoundStatement>(&s) {
+    Ok(cst) => {
+      let mut stream = TokenStream::new();
+      cst.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single [`LayoutQualifier`](glsl::syntax::LayoutQualifier).
+///
+/// Accepts the same leading `@crate(path)` directive as [`glsl!`].
+///
+/// ```
+/// use glsl::syntax::LayoutQualifier;
+/// use glsl_quasiquote::glsl_layout;
+///
+/// let _: LayoutQualifier = glsl_layout! { layout(location = 0, std140) };
+/// ```
+#[proc_macro]
+pub fn glsl_layout(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  match parse_fully::<syntax::LayoutQualifier>(&s) {
+    Ok(l) => {
+      let mut stream = TokenStream::new();
+      l.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single [`TypeQualifier`](glsl::syntax::TypeQualifier), combining any mix of layout,
+/// storage, precision, interpolation, invariant and precise qualifiers.
+///
+/// Accepts the same leading `@crate(path)` directive as [`glsl!`].
+///
+/// ```
+/// use glsl::syntax::TypeQualifier;
+/// use glsl_quasiquote::glsl_type_qualifier;
+///
+/// let _: TypeQualifier = glsl_type_qualifier! { layout(std430) buffer readonly };
+/// ```
+#[proc_macro]
+pub fn glsl_type_qualifier(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  match parse_fully::<syntax::TypeQualifier>(&s) {
+    Ok(q) => {
+      let mut stream = TokenStream::new();
+      q.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single [`InitDeclaratorList`](glsl::syntax::InitDeclaratorList): a typed head
+/// declarator followed by zero or more typeless tail declarators sharing its type, e.g.
+/// `vec3 a = vec3(0.0), b, c`.
+///
+/// Accepts the same leading `@crate(path)` directive and `#name` holes as [`glsl!`].
+///
+/// ```
+/// use glsl::syntax::InitDeclaratorList;
+/// use glsl_quasiquote::glsl_init_list;
+///
+/// let _: InitDeclaratorList = glsl_init_list! { vec3 a = vec3(0.0), b, c[3] };
+/// ```
+#[proc_macro]
+pub fn glsl_init_list(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  match parse_fully::<syntax::InitDeclaratorList>(&s) {
+    Ok(l) => {
+      let mut stream = TokenStream::new();
+      l.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single [`Initializer`](glsl::syntax::Initializer): a declaration's `= <expr>` right-hand
+/// side, either a single expression ([`Initializer::Simple`](glsl::syntax::Initializer::Simple))
+/// or a brace-delimited, possibly nested list of them
+/// ([`Initializer::List`](glsl::syntax::Initializer::List)).
+///
+/// Accepts the same leading `@crate(path)` directive and `#name` holes as [`glsl!`]. Like
+/// [`glsl_jump!`], there's no sibling variant to reject here — both forms parse straight to an
+/// [`Initializer`](glsl::syntax::Initializer). Useful for splicing a computed initializer into a
+/// declaration built with [`glsl_declaration!`] or [`glsl_init_list!`].
+///
+/// ```
+/// use glsl::syntax::Initializer;
+/// use glsl_quasiquote::glsl_initializer;
+///
+/// let _: Initializer = glsl_initializer! { vec3(0.0) };
+/// let _: Initializer = glsl_initializer! { { 1.0, 2.0, 3.0 } };
+/// let _: Initializer = glsl_initializer! { { { 1.0, 0.0 }, { 0.0, 1.0 } } };
+/// ```
+#[proc_macro]
+pub fn glsl_initializer(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  match parse_fully::<syntax::Initializer>(&s) {
+    Ok(init) => {
+      let mut stream = TokenStream::new();
+      init.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single `for` loop as a [`syntax::IterationStatement::For`].
+///
+/// Accepts the same leading `@crate(path)` directive and `#name` holes as [`glsl!`]. A `while` or
+/// `do`-`while` loop parses fine as an [`IterationStatement`](glsl::syntax::IterationStatement)
+/// too, but isn't what this macro is for, so it's rejected with a clear message rather than
+/// silently handed back as the "wrong" kind of loop:
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl_for;
+///
+/// let _ = glsl_for! { while (true) { } };
+/// ```
+///
+/// ```
+/// use glsl::syntax::IterationStatement;
+/// use glsl_quasiquote::glsl_for;
+///
+/// let _: IterationStatement = glsl_for! {
+///   for (int i = 0; i < 10; i++) {
+///     accumulate(i);
+///   }
+/// };
+/// ```
+#[proc_macro]
+pub fn glsl_for(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  match parse_fully::<syntax::IterationStatement>(&s) {
+    Ok(ist @ syntax::IterationStatement::For(..)) => {
+      let mut stream = TokenStream::new();
+      ist.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Ok(_) => {
+      quote! { compile_error!("glsl_for! only accepts a `for` loop, not `while`/`do-while`") }
+        .into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single [`SwitchStatement`](glsl::syntax::SwitchStatement).
+///
+/// Accepts the same leading `@crate(path)` directive and `#name` holes as [`glsl!`]. An empty
+/// body, and a body consisting only of a `default` label, both tokenize fine — `body` is just a
+/// `Vec<Statement>`, with case/default labels mixed in as ordinary statements, so there's no
+/// "must have at least one case" grammar rule to trip over.
+///
+/// ```
+/// use glsl::syntax::SwitchStatement;
+/// use glsl_quasiquote::glsl_switch;
+///
+/// let _: SwitchStatement = glsl_switch! {
+///   switch (mode) {
+///     case 0:
+///       a();
+///       break;
+///     default:
+///       b();
+///   }
+/// };
+/// ```
+#[proc_macro]
+pub fn glsl_switch(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  match parse_fully::<syntax::SwitchStatement>(&s) {
+    Ok(sst) => {
+      let mut stream = TokenStream::new();
+      sst.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single [`CaseLabel`](glsl::syntax::CaseLabel): `case <expr>:` or `default:`.
+///
+/// Accepts the same leading `@crate(path)` directive and `#name` holes as [`glsl!`]. Like
+/// [`glsl_jump!`], there's no sibling variant to reject here — both forms parse straight to a
+/// [`CaseLabel`](glsl::syntax::CaseLabel). Useful for assembling a
+/// [`SwitchStatement`](glsl::syntax::SwitchStatement)'s `body` (a plain `Vec<Statement>`, wrapping
+/// each label in `Statement::Simple(Box::new(SimpleStatement::CaseLabel(..)))`) out of computed
+/// case labels one at a time, rather than writing the whole `switch` with [`glsl_switch!`] at
+/// once.
+///
+/// ```
+/// use glsl::syntax::CaseLabel;
+/// use glsl_quasiquote::glsl_case;
+///
+/// let _: CaseLabel = glsl_case! { case 3: };
+/// let _: CaseLabel = glsl_case! { default: };
+/// ```
+#[proc_macro]
+pub fn glsl_case(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  match parse_fully::<syntax::CaseLabel>(&s) {
+    Ok(cl) => {
+      let mut stream = TokenStream::new();
+      cl.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single [`SelectionStatement`](glsl::syntax::SelectionStatement): a standalone
+/// `if`/`else`.
+///
+/// Accepts the same leading `@crate(path)` directive and `#name` holes as [`glsl!`]. Both
+/// [`SelectionRestStatement`](glsl::syntax::SelectionRestStatement) forms work: a bare `if`, and
+/// an `if`/`else`. Like [`glsl_jump!`], there's no sibling variant to reject here — anything that
+/// isn't a [`SelectionStatement`](glsl::syntax::SelectionStatement) is already a plain parse
+/// error, since `if` is the only keyword this grammar rule starts on:
+///
+/// ```
+/// use glsl::syntax::SelectionStatement;
+/// use glsl_quasiquote::glsl_selection;
+///
+/// let _: SelectionStatement = glsl_selection! {
+///   if (c) {
+///     a();
+///   } else {
+///     b();
+///   }
+/// };
+///
+/// let _: SelectionStatement = glsl_selection! {
+///   if (c) a();
+/// };
+/// ```
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl_selection;
+///
+/// let _ = glsl_selection! { a(); };
+/// ```
+#[proc_macro]
+pub fn glsl_selection(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  match parse_fully::<syntax::SelectionStatement>(&s) {
+    Ok(sst) => {
+      let mut stream = TokenStream::new();
+      sst.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single [`Declaration`](glsl::syntax::Declaration): a function prototype, a variable
+/// declaration, a `precision` statement, an interface block, or a `Global` qualifier declaration.
+///
+/// Accepts the same leading `@crate(path)` directive and `#name` holes as [`glsl!`]. Like
+/// [`glsl_jump!`], there's no sibling variant to reject here — [`Declaration`](glsl::syntax::Declaration)
+/// is itself the top-level grammar rule every one of its variants parses through, so anything that
+/// isn't one of them is already a plain parse error. Prefer one of the narrower macros
+/// ([`glsl_precision!`], [`glsl_block!`]) when you know which variant you want and would otherwise
+/// have to match it back out of the returned [`Declaration`](glsl::syntax::Declaration) yourself.
+///
+/// ```
+/// use glsl::syntax::Declaration;
+/// use glsl_quasiquote::glsl_declaration;
+///
+/// let _: Declaration = glsl_declaration! { float x = 1.0; };
+/// let _: Declaration = glsl_declaration! { void f(float a); };
+/// ```
+#[proc_macro]
+pub fn glsl_declaration(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  match parse_fully::<syntax::Declaration>(&s) {
+    Ok(decl) => {
+      let mut stream = TokenStream::new();
+      decl.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single interface block, as the inner [`syntax::Block`] rather than the
+/// [`Declaration::Block`](glsl::syntax::Declaration::Block) [`glsl_declaration!`] would wrap it in
+/// — a UBO, SSBO, or other `layout(...) uniform/buffer Name { ... } instance[n];` block, with or
+/// without its optional instance identifier (and that identifier's optional array specifier).
+///
+/// Accepts the same leading `@crate(path)` directive and `#name` holes as [`glsl!`]. There's no
+/// `block` grammar rule in [`glsl`] with its own [`Parse`] impl to call into directly (the same
+/// situation [`glsl_param!`] is in), so the source is instead parsed as a
+/// [`Declaration`](glsl::syntax::Declaration) and the inner `Block` pulled back out, rejecting
+/// every other `Declaration` variant with a clear message:
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl_block;
+///
+/// let _ = glsl_block! { float x; };
+/// ```
+///
+/// ```
+/// use glsl::syntax::Block;
+/// use glsl_quasiquote::glsl_block;
+///
+/// let _: Block = glsl_block! {
+///   layout(std140) uniform Camera {
+///     mat4 vp;
+///   } cam;
+/// };
+///
+/// let _: Block = glsl_block! {
+///   layout(std140) uniform Camera {
+///     mat4 vp;
+///   };
+/// };
+/// ```
+#[proc_macro]
+pub fn glsl_block(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  match parse_fully::<syntax::Declaration>(&s) {
+    Ok(syntax::Declaration::Block(block)) => {
+      let mut stream = TokenStream::new();
+      block.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Ok(_) => quote! { compile_error!("glsl_block! only accepts an interface block") }.into(),
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single `precision` declaration, as a [`syntax::Declaration::Precision`].
+///
+/// ES shaders open with one or more of these to set the default precision for a type, e.g.
+/// `precision highp float;`. Accepts the same leading `@crate(path)` directive and `#name` holes
+/// as [`glsl!`]. A [`Declaration`](glsl::syntax::Declaration) has other, unrelated forms (a
+/// function prototype, a plain variable declaration, a block, ...), so those are rejected with a
+/// clear message rather than silently handed back as the "wrong" kind of declaration:
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl_precision;
+///
+/// let _ = glsl_precision! { float x; };
+/// ```
+///
+/// ```
+/// use glsl::syntax::Declaration;
+/// use glsl_quasiquote::glsl_precision;
+///
+/// let _: Declaration = glsl_precision! { precision highp float; };
+/// ```
+#[proc_macro]
+pub fn glsl_precision(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  match parse_fully::<syntax::Declaration>(&s) {
+    Ok(decl @ syntax::Declaration::Precision(..)) => {
+      let mut stream = TokenStream::new();
+      decl.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Ok(_) => {
+      quote! { compile_error!("glsl_precision! only accepts a `precision` declaration") }.into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single [`JumpStatement`](glsl::syntax::JumpStatement): `break`, `continue`,
+/// `discard`, or `return` (with or without a value).
+///
+/// Accepts the same leading `@crate(path)` directive and `#name` holes as [`glsl!`]. Unlike
+/// [`glsl_for!`] or [`glsl_precision!`], there's no "wrong variant" to reject here — all four
+/// forms parse as a [`JumpStatement`](glsl::syntax::JumpStatement), so anything that isn't one of
+/// them is already a plain parse error.
+///
+/// ```
+/// use glsl::syntax::JumpStatement;
+/// use glsl_quasiquote::glsl_jump;
+///
+/// let _: JumpStatement = glsl_jump! { continue; };
+/// let _: JumpStatement = glsl_jump! { break; };
+/// let _: JumpStatement = glsl_jump! { discard; };
+/// let _: JumpStatement = glsl_jump! { return; };
+/// let _: JumpStatement = glsl_jump! { return x + 1.0; };
+/// ```
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl_jump;
+///
+/// let _ = glsl_jump! { x + 1.0; };
+/// ```
+#[proc_macro]
+pub fn glsl_jump(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  match parse_fully::<syntax::JumpStatement>(&s) {
+    Ok(jst) => {
+      let mut stream = TokenStream::new();
+      jst.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single [`FunctionParameterDeclaration`](glsl::syntax::FunctionParameterDeclaration),
+/// e.g. `in const float x` or a bare, unnamed `float`.
+///
+/// Accepts the same leading `@crate(path)` directive and `#name` holes as [`glsl!`]. Both the
+/// `Named` (with an identifier) and `Unnamed` (type only) forms are supported, including their
+/// shared optional leading qualifier. There's no `function_parameter_declaration` grammar rule in
+/// [`glsl`] with its own [`Parse`] impl to call into directly (unlike every other small-piece
+/// macro in this crate), so the source is instead parsed as the parameter list of a throwaway
+/// [`FunctionPrototype`](glsl::syntax::FunctionPrototype) and the single parameter pulled back out
+/// — this is exactly the entry point [`glsl_function!`]'s `#(#name)` parameter splice needs to
+/// populate its spliced `Vec`:
+///
+/// ```
+/// use glsl::syntax::FunctionParameterDeclaration;
+/// use glsl_quasiquote::glsl_param;
+///
+/// let _: FunctionParameterDeclaration = glsl_param! { in const float x };
+/// let _: FunctionParameterDeclaration = glsl_param! { float };
+/// ```
+#[proc_macro]
+pub fn glsl_param(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+  let wrapped = format!("void __glsl_quasiquote_param_wrapper({})", s);
+
+  match parse_fully::<syntax::FunctionPrototype>(&wrapped) {
+    Ok(ref fp) if fp.parameters.len() == 1 => {
+      let mut stream = TokenStream::new();
+      fp.parameters[0].tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Ok(_) => {
+      quote! { compile_error!("glsl_param! accepts exactly one parameter declaration") }.into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Create a single [`FunctionDefinition`](glsl::syntax::FunctionDefinition): a function prototype
+/// plus its body.
+///
+/// Accepts the same leading `@crate(path)` directive and `#name` holes as [`glsl!`]. The
+/// parameter list also accepts a `#(#name)` splice (`name: Vec<FunctionParameterDeclaration>`),
+/// which can be mixed freely with literal parameters and may expand to zero parameters — unlike
+/// every other splice point in this crate (a declaration list, a struct's field list, a call's
+/// argument list), a splice here is only recognized in the parameter list itself, not anywhere
+/// inside the body, since [`glsl_function!`]'s fixed grammar is the only place this crate can tell
+/// "first top-level parenthesized list" apart from an ordinary call's arguments:
+///
+/// ```
+/// use glsl::syntax::{FunctionParameterDeclaration, FunctionParameterDeclarator, TypeSpecifier};
+/// use glsl_quasiquote::glsl_function;
+///
+/// let extra = vec![FunctionParameterDeclaration::Named(
+///   None,
+///   FunctionParameterDeclarator {
+///     ty: TypeSpecifier::new(glsl::syntax::TypeSpecifierNonArray::Float),
+///     ident: "b".into(),
+///   },
+/// )];
+///
+/// let _ = glsl_function! {
+///   void f(float a, #(#extra)) {
+///     a = a + b;
+///   }
+/// };
+///
+/// let empty: Vec<FunctionParameterDeclaration> = Vec::new();
+///
+/// let _ = glsl_function! {
+///   void g(#(#empty)) {
+///   }
+/// };
+/// ```
+///
+/// The function body position itself also accepts a bare `#name` hole
+/// (`name: CompoundStatement`), standing in for the function's whole body in one splice — unlike
+/// everywhere else in this crate, this is the one place a hole can stand for a whole
+/// `CompoundStatement` rather than a single declaration, expression, or qualifier, since
+/// [`glsl_function!`]'s fixed grammar guarantees that slot is always exactly one `CompoundStatement`
+/// rather than a list something could be spliced into:
+///
+/// ```
+/// use glsl::syntax::CompoundStatement;
+/// use glsl_quasiquote::{glsl_compound, glsl_function};
+///
+/// let body: CompoundStatement = glsl_compound! {
+///   { return; }
+/// };
+///
+/// let _ = glsl_function! {
+///   void main() #body
+/// };
+/// ```
+#[proc_macro]
+pub fn glsl_function(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+  let s = match comments::render(input.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes_for_function_prototype(&s);
+
+  match parse_fully::<syntax::FunctionDefinition>(&s) {
+    Ok(fd) => {
+      let mut stream = TokenStream::new();
+      fd.tokenize(&mut stream);
+
+      crate_path::rewrite(stream, &path).into()
+    }
+    Err(e) => glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  }
+}
+
+/// Declare a function that lazily parses a [`TranslationUnit`] once and hands back a `&'static`
+/// reference to it on every call after that, for storing a shader at module scope without
+/// re-parsing it on every access.
+///
+/// ```
+/// use glsl_quasiquote::glsl_const;
+///
+/// glsl_const! {
+///   fn shader() {
+///     void main() {}
+///   }
+/// }
+///
+/// let tu = shader();
+/// assert!(std::ptr::eq(tu, shader())); // parsed once, the same reference every time
+/// ```
+///
+/// expands to (roughly):
+///
+/// ```ignore
+/// fn shader() -> &'static glsl::syntax::TranslationUnit {
+///   static CELL: std::sync::OnceLock<glsl::syntax::TranslationUnit> = std::sync::OnceLock::new();
+///   CELL.get_or_init(|| /* the usual, non-const TranslationUnit construction */)
+/// }
+/// ```
+///
+/// A leading visibility (`pub`, `pub(crate)`, ...) before `fn` is forwarded to the generated
+/// function, and the same leading `@crate(path)` directive and `#name` holes as [`glsl!`] are
+/// accepted before it.
+///
+/// # Why not a `const`/`static` item directly
+///
+/// The obvious API would be `static SHADER: TranslationUnit = glsl_const!{...};`, matching how
+/// [`glsl!`] reads. That's not achievable here, for two independent reasons:
+///
+/// - [`TranslationUnit`] (and everything it's built from) is an ordinary `glsl` crate type using
+///   `String`, `Vec` and `Box` throughout; none of those have a `const fn` constructor, so no
+///   expression built from one can ever be `const`-evaluated, no matter how it's wrapped. Getting
+///   a truly `const`-constructible shader AST would mean forking the whole [`syntax`](glsl::syntax)
+///   module onto `&'static str`/array-slice-backed types — a different, much larger crate, not a
+///   mode switch on top of this one.
+/// - Even the weaker "build it once, lazily, behind a `static`" version can't be spelled as a bare
+///   expression standing in for a static's initializer: the cheap part (a `static CELL: OnceLock<_>
+///   = OnceLock::new();`) is `const`-evaluable on its own, but the expensive part (the closure that
+///   actually parses and constructs the shader) has to be defined somewhere that's still in scope
+///   every time the value is read, which an expression substituted into someone else's `static ... =
+///   <here>;` can't arrange — there's nowhere for that closure to live but inside a function body.
+///   Expanding to a function, as this macro does, is what lets both pieces share one scope.
+///
+/// [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+#[proc_macro]
+pub fn glsl_const(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+  let (path, input) = match crate_path::take_directive(input.into()) {
+    Ok(v) => v,
+    Err(err) => return err,
+  };
+
+  let ConstFn { vis, name, body } = match syn::parse2(input.clone()) {
+    Ok(c) => c,
+    Err(e) => return e.to_compile_error().into(),
+  };
+
+  let s = match comments::render(body) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  if s.trim().is_empty() {
+    return quote! { compile_error!("glsl_const! requires at least one declaration") }.into();
+  }
+
+  let built = match try_quote_str(&s) {
+    Ok(stream) => stream,
+    Err(e) => return glsl_quote_error_to_compile_error(e, span, &s, &input).into(),
+  };
+
+  let stream = quote! {
+    #vis fn #name() -> &'static ::glsl::syntax::TranslationUnit {
+      static __GLSL_QUASIQUOTE_CONST_CELL: ::std::sync::OnceLock<::glsl::syntax::TranslationUnit> =
+        ::std::sync::OnceLock::new();
+
+      __GLSL_QUASIQUOTE_CONST_CELL.get_or_init(|| #built)
+    }
+  };
+
+  crate_path::rewrite(stream, &path).into()
+}
+
+/// `glsl_const!`'s input: an optionally-visible, parameterless `fn` item whose body holds the
+/// GLSL source to parse, e.g. `pub fn shader() { void main() {} }`.
+struct ConstFn {
+  vis: syn::Visibility,
+  name: syn::Ident,
+  body: TokenStream,
+}
+
+impl syn::parse::Parse for ConstFn {
+  fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+    let vis: syn::Visibility = input.parse()?;
+    input.parse::<syn::Token![fn]>()?;
+    let name: syn::Ident = input.parse()?;
+
+    let params;
+    syn::parenthesized!(params in input);
+    if !params.is_empty() {
+      return Err(params.error("glsl_const! functions take no parameters"));
+    }
+
+    let body_tokens;
+    syn::braced!(body_tokens in input);
+    let body: TokenStream = body_tokens.parse()?;
+
+    Ok(ConstFn { vis, name, body })
+  }
+}
+
+/// Create a [`TranslationUnit`], the same way [`glsl!`] does, but first check it against a small,
+/// hand-maintained deny-list of built-ins that only make sense in some other shader stage —
+/// catching the common mistake of pasting a fragment shader's body into a vertex one (or the
+/// reverse) and missing a leftover `gl_FragColor`/`gl_Position`/etc.
+///
+/// ```
+/// use glsl_quasiquote::glsl_stage;
+///
+/// let _ = glsl_stage!(vertex, {
+///   void main() {
+///     gl_Position = vec4(0.0, 0.0, 0.0, 1.0);
+///   }
+/// });
+/// ```
+///
+/// Referencing a built-in from the wrong stage is a `compile_error!` naming the offending
+/// identifier, rather than a runtime shader-compiler error you'd only see against a real GPU:
+///
+/// ```compile_fail
+/// use glsl_quasiquote::glsl_stage;
+///
+/// let _ = glsl_stage!(fragment, {
+///   void main() {
+///     gl_Position = vec4(0.0, 0.0, 0.0, 1.0);
+///   }
+/// });
+/// ```
+///
+/// This only covers `vertex` and `fragment` for now (any other first argument, including the
+/// tessellation/geometry/compute stages, is itself a `compile_error!` naming the two that are
+/// supported) and only the handful of built-ins above: unlike every other check in this crate,
+/// there's no structured list of "which built-in belongs to which stage" to draw on — the upstream
+/// [`glsl`] crate's AST treats a built-in identifier exactly like any other name, so completing
+/// this would mean transcribing the whole built-in variable table from the GLSL specification by
+/// hand. A small, commonly-mismatched deny-list per stage is the scoped-down version of that,
+/// tracked in [`stage`](crate::stage), and is meant to catch copy-paste mistakes, not to replace
+/// validating against a real shader compiler.
+///
+/// Accepts the same `#name`-style holes as [`glsl!`] (but not the leading `@crate(path)`
+/// directive, since the stage name already occupies the position a directive would): the body is
+/// everything after the stage name and its comma, braced the same way a [`CompoundStatement`]'s
+/// block is, just so `syn` has an unambiguous way to separate it from the stage argument.
+///
+/// [`TranslationUnit`]: https://docs.rs/glsl/1.0.0/glsl/syntax/struct.TranslationUnit.html
+/// [`CompoundStatement`]: glsl::syntax::CompoundStatement
+#[proc_macro]
+pub fn glsl_stage(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let span = invocation_span(&input);
+
+  let StageInput { stage, body } = match syn::parse(input) {
+    Ok(s) => s,
+    Err(e) => return e.to_compile_error().into(),
+  };
+
+  let stage = match stage::Stage::parse(&stage.to_string()) {
+    Ok(stage) => stage,
+    Err(message) => return quote! { compile_error!(#message); }.into(),
+  };
+
+  let s = match comments::render(body.clone()) {
+    Ok(s) => s,
+    Err(err) => return err,
+  };
+  let s = holes::extract_holes(&s);
+
+  if s.trim().is_empty() {
+    return quote! { compile_error!("glsl_stage! requires at least one declaration") }.into();
+  }
+
+  let tu = match parse_translation_unit_fully(&s) {
+    Ok(tu) => tu,
+    Err(e) => return glsl_quote_error_to_compile_error(e, span, &s, &body).into(),
+  };
+
+  let offenders = stage::check(&tu, stage);
+
+  if !offenders.is_empty() {
+    let message = format!(
+      "glsl_stage!: found built-in(s) {} that don't belong in a {:?} shader",
+      offenders.join(", "),
+      stage
+    );
+    return quote! { compile_error!(#message); }.into();
+  }
+
+  let mut stream = TokenStream::new();
+  tu.tokenize(&mut stream);
+  stream.into()
+}
+
+/// [`glsl_stage!`]'s input: a stage name, a comma, and a braced GLSL body, e.g.
+/// `vertex, { void main() {} }`.
+struct StageInput {
+  stage: syn::Ident,
+  body: TokenStream,
+}
+
+impl syn::parse::Parse for StageInput {
+  fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+    let stage: syn::Ident = input.parse()?;
+    input.parse::<syn::Token![,]>()?;
+
+    let body_tokens;
+    syn::braced!(body_tokens in input);
+    let body: TokenStream = body_tokens.parse()?;
+
+    if !input.is_empty() {
+      return Err(input.error("glsl_stage! takes a stage name and a single braced GLSL body"));
+    }
+
+    Ok(StageInput { stage, body })
+  }
+}
+
+/// Assert that two expressions are equal, the way [`assert_eq!`] does, but on mismatch panic with
+/// the two sides pretty-printed (`{:#?}`) one line at a time, each line prefixed `-`/`+`/` `
+/// depending on whether it only appears on the left, only on the right, or on both, instead of
+/// `assert_eq!`'s own single-line [`Debug`] dump — unreadable once the two sides are a whole
+/// [`TranslationUnit`](glsl::syntax::TranslationUnit).
+///
+/// `assert_glsl_eq!(produced, glsl! { ... })` reads like [`assert_eq!`], but this can't actually
+/// be an ordinary `macro_rules!` exported with `#[macro_export]`: this crate is `proc-macro =
+/// true`, and a proc-macro crate is only allowed to export `#[proc_macro]`/
+/// `#[proc_macro_derive]`/`#[proc_macro_attribute]` functions — `rustc` rejects any other exported
+/// item, `macro_rules!` included, no matter how it's marked. So this is itself a function-like
+/// proc macro that expands to the comparison inline, the same way [`glsl_expr!`] or
+/// [`glsl_jump!`] expand to a value in expression position.
+///
+/// Lines are compared by position, not matched up the way a real diff (e.g. Myers') would — a
+/// one-line insertion partway through shifts every following line to a `-`/`+` pair instead of
+/// lining back up. That's a real limitation, but a parsed GLSL tree's `{:#?}` output rarely
+/// reorders or inserts lines for a small change (it's typically one or two fields differing deep
+/// in otherwise-identical structure), so in practice this still puts the actual mismatch front and
+/// center instead of drowning it in two walls of text.
+///
+/// ```
+/// use glsl_quasiquote::{assert_glsl_eq, glsl};
+///
+/// let produced = glsl! { void main() {} };
+///
+/// assert_glsl_eq!(produced, glsl! { void main() {} });
+/// ```
+///
+/// ```should_panic
+/// use glsl_quasiquote::{assert_glsl_eq, glsl};
+///
+/// let produced = glsl! { void main() {} };
+///
+/// assert_glsl_eq!(produced, glsl! { void other() {} });
+/// ```
+#[proc_macro]
+pub fn assert_glsl_eq(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+  let AssertGlslEq { left, right } = match syn::parse(input) {
+    Ok(a) => a,
+    Err(e) => return e.to_compile_error().into(),
+  };
+
+  let stream = quote! {
+    {
+      let __glsl_quasiquote_left = #left;
+      let __glsl_quasiquote_right = #right;
+
+      if __glsl_quasiquote_left != __glsl_quasiquote_right {
+        let __glsl_quasiquote_left_str = format!("{:#?}", __glsl_quasiquote_left);
+        let __glsl_quasiquote_right_str = format!("{:#?}", __glsl_quasiquote_right);
+        let __glsl_quasiquote_left_lines: Vec<&str> = __glsl_quasiquote_left_str.lines().collect();
+        let __glsl_quasiquote_right_lines: Vec<&str> =
+          __glsl_quasiquote_right_str.lines().collect();
+
+        let mut __glsl_quasiquote_diff = String::new();
+
+        for i in 0..__glsl_quasiquote_left_lines
+          .len()
+          .max(__glsl_quasiquote_right_lines.len())
+        {
+          match (
+            __glsl_quasiquote_left_lines.get(i),
+            __glsl_quasiquote_right_lines.get(i),
+          ) {
+            (Some(l), Some(r)) if l == r => {
+              __glsl_quasiquote_diff.push_str("  ");
+              __glsl_quasiquote_diff.push_str(l);
+              __glsl_quasiquote_diff.push('\n');
+            }
+            (Some(l), Some(r)) => {
+              __glsl_quasiquote_diff.push_str("- ");
+              __glsl_quasiquote_diff.push_str(l);
+              __glsl_quasiquote_diff.push('\n');
+              __glsl_quasiquote_diff.push_str("+ ");
+              __glsl_quasiquote_diff.push_str(r);
+              __glsl_quasiquote_diff.push('\n');
+            }
+            (Some(l), None) => {
+              __glsl_quasiquote_diff.push_str("- ");
+              __glsl_quasiquote_diff.push_str(l);
+              __glsl_quasiquote_diff.push('\n');
+            }
+            (None, Some(r)) => {
+              __glsl_quasiquote_diff.push_str("+ ");
+              __glsl_quasiquote_diff.push_str(r);
+              __glsl_quasiquote_diff.push('\n');
+            }
+            (None, None) => {}
+          }
+        }
+
+        panic!("assertion `left == right` failed\n{}", __glsl_quasiquote_diff);
+      }
+    }
+  };
+
+  stream.into()
+}
+
+/// [`assert_glsl_eq!`]'s input: two comma-separated expressions, with an optional trailing comma,
+/// the same shape [`assert_eq!`] itself accepts.
+struct AssertGlslEq {
+  left: syn::Expr,
+  right: syn::Expr,
+}
+
+impl syn::parse::Parse for AssertGlslEq {
+  fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+    let left: syn::Expr = input.parse()?;
+    input.parse::<syn::Token![,]>()?;
+    let right: syn::Expr = input.parse()?;
+
+    if input.peek(syn::Token![,]) {
+      input.parse::<syn::Token![,]>()?;
+    }
+
+    if !input.is_empty() {
+      return Err(input.error("assert_glsl_eq! takes exactly two expressions"));
+    }
+
+    Ok(AssertGlslEq { left, right })
+  }
+}
@@ -0,0 +1,70 @@
+//! Stage-specific built-in deny-lists for [`crate::glsl_stage!`].
+//!
+//! This is deliberately a small, hand-maintained list of the most commonly copy-paste-swapped
+//! built-ins, not an attempt at a full GLSL built-in variable database: the upstream [`glsl`] crate
+//! doesn't distinguish "vertex-only" from "fragment-only" identifiers in its own AST (a built-in is
+//! just an [`Identifier`](syntax::Identifier) like any other name, spelled however the source
+//! spelled it), so there's no structured source to draw a complete list from short of transcribing
+//! the GLSL specification by hand.
+
+use glsl::syntax;
+use glsl::visitor::{Host, Visit, Visitor};
+
+/// The shader stage a [`crate::glsl_stage!`] body is declared for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stage {
+  Vertex,
+  Fragment,
+}
+
+impl Stage {
+  /// Parse a stage name as it appears in `glsl_stage!(name, { .. })`, e.g. `vertex`.
+  pub fn parse(name: &str) -> Result<Self, String> {
+    match name {
+      "vertex" => Ok(Stage::Vertex),
+      "fragment" => Ok(Stage::Fragment),
+      other => Err(format!(
+        "glsl_stage! expects `vertex` or `fragment`, got `{}`",
+        other
+      )),
+    }
+  }
+
+  /// The built-ins that are a mistake to reference from this stage, because they only make sense
+  /// in some other one.
+  fn deny_list(self) -> &'static [&'static str] {
+    match self {
+      Stage::Vertex => &["gl_FragColor", "gl_FragCoord", "gl_FragDepth", "gl_FrontFacing"],
+      Stage::Fragment => &["gl_Position", "gl_PointSize", "gl_VertexID", "gl_InstanceID"],
+    }
+  }
+}
+
+/// Walk `tu` looking for identifiers [`Stage::deny_list`] flags for `stage`, returning every
+/// distinct one found, in the order first encountered.
+pub fn check(tu: &syntax::TranslationUnit, stage: Stage) -> Vec<String> {
+  let mut visitor = DenyListVisitor {
+    deny_list: stage.deny_list(),
+    found: Vec::new(),
+  };
+
+  tu.visit(&mut visitor);
+
+  visitor.found
+}
+
+/// A [`Visitor`] collecting every identifier matching a fixed deny-list, used by [`check`].
+struct DenyListVisitor {
+  deny_list: &'static [&'static str],
+  found: Vec<String>,
+}
+
+impl Visitor for DenyListVisitor {
+  fn visit_identifier(&mut self, identifier: &syntax::Identifier) -> Visit {
+    if self.deny_list.contains(&identifier.0.as_str()) && !self.found.iter().any(|f| f == &identifier.0) {
+      self.found.push(identifier.0.clone());
+    }
+
+    Visit::Children
+  }
+}
@@ -0,0 +1,74 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Declaration, Expr, ExternalDeclaration};
+
+#[test]
+fn splices_a_homogeneous_numeric_vec_into_an_array_constructor() {
+  let weights: Vec<f32> = vec![0.1, 0.2, 0.7];
+
+  let tu = glsl! {
+    const float kernel[3] = float[](#(#weights));
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => {
+      match list.head.initializer.as_ref().unwrap() {
+        glsl::syntax::Initializer::Simple(e) => match **e {
+          Expr::FunCall(_, ref args) => {
+            assert_eq!(
+              args,
+              &vec![
+                Expr::FloatConst(0.1),
+                Expr::FloatConst(0.2),
+                Expr::FloatConst(0.7),
+              ]
+            );
+          }
+          ref other => panic!("expected a function call, got {:?}", other),
+        },
+        other => panic!("expected a simple initializer, got {:?}", other),
+      }
+    }
+    other => panic!("expected a declaration, got {:?}", other),
+  }
+}
+
+#[test]
+fn splices_mixed_with_literal_arguments_preserving_order() {
+  let rest: Vec<f32> = vec![2.0, 3.0];
+
+  let tu = glsl! {
+    const float kernel[3] = float[](1.0, #(#rest));
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => {
+      match list.head.initializer.as_ref().unwrap() {
+        glsl::syntax::Initializer::Simple(e) => match **e {
+          Expr::FunCall(_, ref args) => {
+            assert_eq!(
+              args,
+              &vec![Expr::FloatConst(1.0), Expr::FloatConst(2.0), Expr::FloatConst(3.0)]
+            );
+          }
+          ref other => panic!("expected a function call, got {:?}", other),
+        },
+        other => panic!("expected a simple initializer, got {:?}", other),
+      }
+    }
+    other => panic!("expected a declaration, got {:?}", other),
+  }
+}
+
+#[test]
+#[should_panic(expected = "array size does not match the spliced initializer list's length")]
+fn a_spliced_array_size_mismatching_the_spliced_initializer_list_length_panics() {
+  let n = 4u32;
+  let weights: Vec<f32> = vec![0.1, 0.2, 0.7];
+
+  let _ = glsl! {
+    const float kernel[#n] = float[](#(#weights));
+  };
+}
@@ -0,0 +1,18 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+#[test]
+fn equal_translation_units_do_not_panic() {
+  let produced = glsl! { void main() {} };
+
+  assert_glsl_eq!(produced, glsl! { void main() {} });
+}
+
+#[test]
+#[should_panic(expected = "assertion `left == right` failed")]
+fn mismatched_translation_units_panic_with_a_diff() {
+  let produced = glsl! { void main() {} };
+
+  assert_glsl_eq!(produced, glsl! { void other() {} });
+}
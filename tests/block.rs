@@ -0,0 +1,50 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{ArraySpecifier, ArraySpecifierDimension, ArrayedIdentifier, NonEmpty};
+
+#[test]
+fn glsl_block_parses_an_interface_block_with_a_named_instance() {
+  let block = glsl_block! {
+    layout(std140) uniform Camera {
+      mat4 vp;
+    } cam;
+  };
+
+  assert_eq!(block.name, "Camera".into());
+  assert_eq!(block.fields.len(), 1);
+  assert_eq!(block.identifier, Some(ArrayedIdentifier::new("cam", None)));
+}
+
+#[test]
+fn glsl_block_parses_an_interface_block_with_an_arrayed_instance() {
+  let block = glsl_block! {
+    layout(std140) buffer Particles {
+      vec4 position[];
+    } particles[4];
+  };
+
+  assert_eq!(
+    block.identifier,
+    Some(ArrayedIdentifier::new(
+      "particles",
+      Some(ArraySpecifier {
+        dimensions: NonEmpty(vec![ArraySpecifierDimension::ExplicitlySized(Box::new(
+          4.into()
+        ))])
+      })
+    ))
+  );
+}
+
+#[test]
+fn glsl_block_parses_an_interface_block_with_no_instance() {
+  let block = glsl_block! {
+    layout(std140) uniform Camera {
+      mat4 vp;
+    };
+  };
+
+  assert!(block.identifier.is_none());
+}
@@ -0,0 +1,59 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::Identifier;
+
+#[test]
+fn repeated_identical_invocations_produce_equal_translation_units() {
+  let a = glsl! {
+    void main() {
+    }
+  };
+
+  let b = glsl! {
+    void main() {
+    }
+  };
+
+  assert_eq!(a, b);
+}
+
+#[test]
+fn repeated_invocations_with_different_holes_do_not_leak_into_each_other() {
+  let first: Identifier = "first".into();
+  let tu1 = glsl! {
+    void #first() {
+    }
+  };
+
+  let second: Identifier = "second".into();
+  let tu2 = glsl! {
+    void #second() {
+    }
+  };
+
+  match (&tu1.0 .0[0], &tu2.0 .0[0]) {
+    (
+      glsl::syntax::ExternalDeclaration::FunctionDefinition(a),
+      glsl::syntax::ExternalDeclaration::FunctionDefinition(b),
+    ) => {
+      assert_eq!(a.prototype.name, "first".into());
+      assert_eq!(b.prototype.name, "second".into());
+    }
+    _ => panic!("expected two function definitions"),
+  }
+}
+
+#[test]
+fn a_cached_parse_of_the_same_text_is_reused_by_a_third_macro() {
+  let tu = glsl! {
+    void main() {
+    }
+  };
+
+  let tu_from_str = glsl_str! { "void main() {
+  }" };
+
+  assert_eq!(tu, tu_from_str);
+}
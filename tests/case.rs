@@ -0,0 +1,56 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{CaseLabel, Expr, SimpleStatement, Statement, SwitchStatement};
+
+#[test]
+fn glsl_case_parses_a_case_label() {
+  match glsl_case! { case 3: } {
+    CaseLabel::Case(e) => assert_eq!(*e, Expr::IntConst(3)),
+    other => panic!("expected a case label, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_case_parses_a_default_label() {
+  assert_eq!(glsl_case! { default: }, CaseLabel::Def);
+}
+
+#[test]
+fn glsl_case_accepts_holes() {
+  let n = 4;
+
+  match glsl_case! { case #n: } {
+    CaseLabel::Case(e) => assert_eq!(*e, Expr::IntConst(4)),
+    other => panic!("expected a case label, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_case_labels_assemble_into_a_switch_body_one_at_a_time() {
+  let labels = vec![glsl_case! { case 1: }, glsl_case! { case 2: }, glsl_case! { default: }];
+
+  let body: Vec<Statement> = labels
+    .into_iter()
+    .map(|cl| Statement::Simple(Box::new(SimpleStatement::CaseLabel(cl))))
+    .collect();
+
+  let switch = SwitchStatement {
+    head: Box::new(Expr::Variable("mode".into())),
+    body,
+  };
+
+  match &switch.body[1] {
+    Statement::Simple(s) => match **s {
+      SimpleStatement::CaseLabel(CaseLabel::Case(ref e)) => assert_eq!(**e, Expr::IntConst(2)),
+      ref other => panic!("expected a case label statement, got {:?}", other),
+    },
+    other => panic!("expected a simple statement, got {:?}", other),
+  }
+
+  match &switch.body[2] {
+    Statement::Simple(s) => assert_eq!(**s, SimpleStatement::CaseLabel(CaseLabel::Def)),
+    other => panic!("expected a simple statement, got {:?}", other),
+  }
+}
@@ -0,0 +1,66 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::ExternalDeclaration;
+
+// `//` and `/* */` comments never reach the macro at all -- `rustc`'s tokenizer strips them
+// before a proc macro sees its input -- so these are really pinning that a `glsl!` invocation
+// built around one still reconstructs correctly, not that the comment's text survives anywhere.
+#[test]
+fn line_and_block_comments_in_various_positions_are_dropped_cleanly() {
+  let tu = glsl! {
+    // a leading line comment
+    /* and a leading block comment */
+    uniform float time; // trailing on a declaration
+    void main() {
+      /* inside a block */
+      int x = 1; // trailing on a statement
+    }
+  };
+
+  assert_eq!(tu.0 .0.len(), 2);
+}
+
+// A `///`/`//!` doc comment is different: `rustc` lowers it to a real `#[doc = "..."]` attribute
+// rather than discarding it outright. Directly inside the macro's own `{ }` that's stripped the
+// same way any other comment is -- see `comments`'s module doc for why that's only safe here and
+// not once it's nested inside a block (covered by a `compile_fail` doctest on `glsl!` instead).
+#[test]
+fn a_leading_outer_doc_comment_is_dropped_cleanly() {
+  let tu = glsl! {
+    /// a doc comment before the only declaration
+    void main() {}
+  };
+
+  assert_eq!(tu.0 .0.len(), 1);
+}
+
+#[test]
+fn a_leading_inner_doc_comment_is_dropped_cleanly() {
+  let tu = glsl! {
+    //! an inner doc comment
+    void main() {}
+  };
+
+  assert_eq!(tu.0 .0.len(), 1);
+}
+
+#[test]
+fn a_doc_comment_before_each_of_several_declarations_is_dropped_cleanly() {
+  let tu = glsl! {
+    /// documents the uniform
+    uniform float time;
+    /// documents main
+    void main() {}
+  };
+
+  assert_eq!(tu.0 .0.len(), 2);
+
+  match &tu.0 .0[1] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "main".into());
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
@@ -0,0 +1,52 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{
+  ArraySpecifier, ArraySpecifierDimension, Declaration, Expr, ExternalDeclaration,
+  FullySpecifiedType, TypeSpecifier, TypeSpecifierNonArray,
+};
+
+#[test]
+fn a_plain_declaration_with_no_qualifier_or_array_matches_the_hand_built_value() {
+  let tu = glsl! {
+    float x;
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => {
+      assert_eq!(
+        list.head.ty,
+        FullySpecifiedType::new(TypeSpecifierNonArray::Float)
+      );
+    }
+    other => panic!("expected a declaration, got {:?}", other),
+  }
+}
+
+#[test]
+fn a_function_prototype_with_an_array_return_type_matches_the_hand_built_value() {
+  let tu = glsl! {
+    float[4] make() {
+      return float[](1.0, 2.0, 3.0, 4.0);
+    }
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(
+        def.prototype.ty.ty,
+        TypeSpecifier {
+          ty: TypeSpecifierNonArray::Float,
+          array_specifier: Some(ArraySpecifier {
+            dimensions: glsl::syntax::NonEmpty(vec![ArraySpecifierDimension::ExplicitlySized(
+              Box::new(Expr::IntConst(4))
+            )]),
+          }),
+        }
+      );
+      assert_eq!(def.prototype.ty.qualifier, None);
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
@@ -0,0 +1,42 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Statement, StorageQualifier, TypeQualifier, TypeQualifierSpec};
+
+#[test]
+fn glsl_compound_parses_a_brace_delimited_statement_list() {
+  let cst = glsl_compound! {
+    {
+      float x = 1.0;
+      return x;
+    }
+  };
+
+  assert_eq!(cst.statement_list.len(), 2);
+}
+
+#[test]
+fn glsl_compound_accepts_holes() {
+  let qual: Option<TypeQualifier> = Some(TypeQualifier {
+    qualifiers: glsl::syntax::NonEmpty(vec![TypeQualifierSpec::Storage(StorageQualifier::Const)]),
+  });
+
+  let cst = glsl_compound! {
+    {
+      #[#qual] float x = 1.0;
+    }
+  };
+
+  match &cst.statement_list[0] {
+    Statement::Simple(s) => match &**s {
+      glsl::syntax::SimpleStatement::Declaration(glsl::syntax::Declaration::InitDeclaratorList(
+        list,
+      )) => {
+        assert!(list.head.ty.qualifier.is_some());
+      }
+      _ => panic!("expected a declaration"),
+    },
+    _ => panic!("expected a simple statement"),
+  }
+}
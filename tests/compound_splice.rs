@@ -0,0 +1,40 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::CompoundStatement;
+
+#[test]
+fn glsl_function_splices_a_whole_compound_statement_as_its_body() {
+  let body: CompoundStatement = glsl_compound! {
+    {
+      float x = 1.0;
+      return;
+    }
+  };
+
+  let fd = glsl_function! {
+    void main() #body
+  };
+
+  assert_eq!(
+    fd.statement,
+    glsl_compound! {
+      {
+        float x = 1.0;
+        return;
+      }
+    }
+  );
+}
+
+#[test]
+fn glsl_function_splices_an_empty_compound_statement_as_its_body() {
+  let body: CompoundStatement = glsl_compound! { {} };
+
+  let fd = glsl_function! {
+    void empty() #body
+  };
+
+  assert_eq!(fd.statement, glsl_compound! { {} });
+}
@@ -0,0 +1,44 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+glsl_const! {
+  pub fn shader() {
+    void main() {
+      gl_Position = vec4(0.0, 0.0, 0.0, 1.0);
+    }
+  }
+}
+
+#[test]
+fn glsl_const_parses_once_and_reuses_the_result() {
+  let first = shader();
+  let second = shader();
+
+  assert!(
+    std::ptr::eq(first, second),
+    "expected the same TranslationUnit reference on every call"
+  );
+
+  match &first.0 .0[0] {
+    glsl::syntax::ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "main".into());
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
+
+mod nested {
+  glsl_const! {
+    fn colors() {
+      vec4 red;
+      vec4 blue;
+    }
+  }
+
+  #[test]
+  fn a_private_glsl_const_function_is_only_visible_within_its_module() {
+    let tu = colors();
+    assert_eq!(tu.0 .0.len(), 2);
+  }
+}
@@ -0,0 +1,26 @@
+extern crate glsl as renamed_glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+#[test]
+fn glsl_honors_a_crate_path_directive() {
+  let tu = glsl! {
+    @crate(renamed_glsl)
+
+    void main() {
+    }
+  };
+
+  assert_eq!(tu.0 .0.len(), 1);
+}
+
+#[test]
+fn glsl_str_honors_a_crate_path_directive() {
+  let tu = glsl_str! {
+    @crate(renamed_glsl)
+    "void main() {
+    }"
+  };
+
+  assert_eq!(tu.0 .0.len(), 1);
+}
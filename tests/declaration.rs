@@ -0,0 +1,37 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::Declaration;
+
+#[test]
+fn glsl_declaration_accepts_an_init_declarator_list() {
+  let decl = glsl_declaration! { float x = 1.0; };
+
+  assert!(matches!(decl, Declaration::InitDeclaratorList(..)));
+}
+
+#[test]
+fn glsl_declaration_accepts_a_function_prototype() {
+  let decl = glsl_declaration! { void f(float a); };
+
+  assert!(matches!(decl, Declaration::FunctionPrototype(..)));
+}
+
+#[test]
+fn glsl_declaration_accepts_a_precision_statement() {
+  let decl = glsl_declaration! { precision highp float; };
+
+  assert!(matches!(decl, Declaration::Precision(..)));
+}
+
+#[test]
+fn glsl_declaration_accepts_an_interface_block() {
+  let decl = glsl_declaration! {
+    layout(std140) uniform Camera {
+      mat4 vp;
+    } cam;
+  };
+
+  assert!(matches!(decl, Declaration::Block(..)));
+}
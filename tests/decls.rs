@@ -0,0 +1,30 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::ExternalDeclaration;
+
+#[test]
+fn glsl_decls_yields_a_plain_vec() {
+  let decls: Vec<ExternalDeclaration> = glsl_decls! {
+    void main() {
+    }
+
+    void extra() {
+    }
+  };
+
+  assert_eq!(decls.len(), 2);
+}
+
+#[test]
+fn glsl_decls_vec_can_be_extended_at_runtime() {
+  let mut decls: Vec<ExternalDeclaration> = glsl_decls! {
+    void main() {
+    }
+  };
+
+  decls.push(glsl_decls! { void extra() {} }.remove(0));
+
+  assert_eq!(decls.len(), 2);
+}
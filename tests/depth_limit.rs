@@ -0,0 +1,31 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Expr, UnaryOp};
+
+#[test]
+fn a_deeply_nested_expression_under_the_limit_still_tokenizes_correctly() {
+  let e = glsl_expr! {
+    - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - x
+  };
+
+  let mut depth = 0;
+  let mut cur = &e;
+
+  loop {
+    match cur {
+      Expr::Unary(UnaryOp::Minus, inner) => {
+        depth += 1;
+        cur = inner;
+      }
+      Expr::Variable(name) => {
+        assert_eq!(name.as_str(), "x");
+        break;
+      }
+      other => panic!("unexpected expression in the chain: {:?}", other),
+    }
+  }
+
+  assert_eq!(depth, 50);
+}
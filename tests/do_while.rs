@@ -0,0 +1,79 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Expr, IterationStatement, Statement};
+
+#[test]
+fn glsl_compound_round_trips_a_do_while_loop() {
+  let cst = glsl_compound! {
+    {
+      int i = 0;
+      do {
+        i = i + 1;
+      } while (i < 10);
+    }
+  };
+
+  assert_eq!(cst.statement_list.len(), 2);
+
+  match &cst.statement_list[1] {
+    Statement::Simple(s) => match &**s {
+      glsl::syntax::SimpleStatement::Iteration(IterationStatement::DoWhile(body, cond)) => {
+        match &**body {
+          Statement::Compound(compound) => assert_eq!(compound.statement_list.len(), 1),
+          other => panic!("expected a compound body, got {:?}", other),
+        }
+
+        assert_eq!(**cond, Expr::Binary(glsl::syntax::BinaryOp::LT, Box::new(Expr::Variable("i".into())), Box::new(Expr::IntConst(10))));
+      }
+      other => panic!("expected a do-while loop, got {:?}", other),
+    },
+    other => panic!("expected a simple statement, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_compound_round_trips_a_single_statement_do_while_body() {
+  let cst = glsl_compound! {
+    {
+      do i = i + 1; while (i < 10);
+    }
+  };
+
+  match &cst.statement_list[0] {
+    Statement::Simple(s) => match &**s {
+      glsl::syntax::SimpleStatement::Iteration(IterationStatement::DoWhile(body, _)) => {
+        match &**body {
+          Statement::Simple(_) => {}
+          other => panic!("expected a bare statement body, got {:?}", other),
+        }
+      }
+      other => panic!("expected a do-while loop, got {:?}", other),
+    },
+    other => panic!("expected a simple statement, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_compound_accepts_a_hole_in_a_do_while_condition() {
+  let limit = Expr::IntConst(5);
+
+  let cst = glsl_compound! {
+    {
+      do {
+        i = i + 1;
+      } while (#limit);
+    }
+  };
+
+  match &cst.statement_list[0] {
+    Statement::Simple(s) => match &**s {
+      glsl::syntax::SimpleStatement::Iteration(IterationStatement::DoWhile(_, cond)) => {
+        assert_eq!(**cond, Expr::IntConst(5));
+      }
+      other => panic!("expected a do-while loop, got {:?}", other),
+    },
+    other => panic!("expected a simple statement, got {:?}", other),
+  }
+}
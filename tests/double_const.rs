@@ -0,0 +1,69 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::Expr;
+
+fn assert_double(e: Expr, expected: f64) {
+  match e {
+    Expr::DoubleConst(v) => assert_eq!(
+      v.to_bits(),
+      expected.to_bits(),
+      "expected {} to round-trip bit-exactly, got {}",
+      expected,
+      v
+    ),
+    _ => panic!("expected a DoubleConst, got {:?}", e),
+  }
+}
+
+#[test]
+fn double_const_round_trips_a_repeating_decimal() {
+  assert_double(glsl_expr! { 0.1lf }, 0.1);
+}
+
+#[test]
+fn double_const_round_trips_full_f64_precision() {
+  assert_double(glsl_expr! { 9.876543210987654lf }, 9.876543210987654);
+}
+
+#[test]
+#[allow(clippy::unreadable_literal, clippy::excessive_precision)]
+fn double_const_round_trips_a_value_needing_every_significant_digit() {
+  assert_double(glsl_expr! { 123456789.123456789lf }, 123456789.123456789);
+}
+
+#[test]
+fn double_const_round_trips_an_extreme_magnitude() {
+  assert_double(glsl_expr! { 1.7976931348623157e308lf }, f64::MAX);
+}
+
+#[test]
+fn double_const_round_trips_through_a_whole_declaration() {
+  let tu = glsl! {
+    void main() {
+      double d = 9.876543210987654lf;
+    }
+  };
+
+  let main = match &tu.0 .0[0] {
+    glsl::syntax::ExternalDeclaration::FunctionDefinition(def) => def,
+    _ => panic!("expected a function definition"),
+  };
+
+  let stmt = &main.statement.statement_list[0];
+  let init = match stmt {
+    glsl::syntax::Statement::Simple(s) => match &**s {
+      glsl::syntax::SimpleStatement::Declaration(glsl::syntax::Declaration::InitDeclaratorList(
+        list,
+      )) => list.head.initializer.as_ref().unwrap(),
+      _ => panic!("expected a declaration"),
+    },
+    _ => panic!("expected a simple statement"),
+  };
+
+  match init {
+    glsl::syntax::Initializer::Simple(e) => assert_double(*e.clone(), 9.876543210987654),
+    _ => panic!("expected a simple initializer"),
+  }
+}
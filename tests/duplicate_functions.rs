@@ -0,0 +1,28 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+// Without the `check-duplicate-functions` feature (the default), two function definitions
+// sharing a name and parameter signature still compile -- the check only rejects this once the
+// feature is enabled, since it's a `compile_error!` and so can't be exercised from a normal
+// `#[test]` in the same build. See the feature's entry in Cargo.toml and `glsl!`'s doc comment.
+#[test]
+#[cfg(not(feature = "check-duplicate-functions"))]
+fn duplicate_function_definitions_are_allowed_by_default() {
+  let tu = glsl! {
+    float add(float a, float b) { return a + b; }
+    float add(float a, float b) { return a + b; }
+  };
+
+  assert_eq!(tu.0 .0.len(), 2);
+}
+
+#[test]
+fn overloading_by_parameter_type_is_never_rejected() {
+  let tu = glsl! {
+    float add(float a, float b) { return a + b; }
+    int add(int a, int b) { return a + b; }
+  };
+
+  assert_eq!(tu.0 .0.len(), 2);
+}
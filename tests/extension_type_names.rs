@@ -0,0 +1,47 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Declaration, ExternalDeclaration, TypeName, TypeSpecifierNonArray};
+
+// `glsl` has no dedicated AST variant for extension-introduced scalar types like `float16_t`
+// (from `GL_AMD_gpu_shader_half_float`/`GL_EXT_shader_16bit_storage`, etc.) -- they parse the
+// same way any user struct name does, as a bare TypeSpecifierNonArray::TypeName, which already
+// reconstructs as an owned ::glsl::syntax::TypeName rather than being dropped or mangled.
+#[test]
+fn an_extension_type_name_round_trips_through_type_specifier_non_array() {
+  let tu = glsl! {
+    float16_t scale(float16_t x) {
+      return x;
+    }
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.ty.ty.ty, TypeSpecifierNonArray::TypeName(TypeName::new("float16_t").unwrap()));
+
+      match &def.prototype.parameters[0] {
+        glsl::syntax::FunctionParameterDeclaration::Named(_, declarator) => {
+          assert_eq!(declarator.ty.ty, TypeSpecifierNonArray::TypeName(TypeName::new("float16_t").unwrap()));
+        }
+        other => panic!("expected a named parameter, got {:?}", other),
+      }
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
+
+#[test]
+fn an_extension_type_name_round_trips_as_a_variable_declaration() {
+  let tu = glsl! {
+    float16_t half_scale;
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => {
+      assert_eq!(list.head.ty.ty.ty, TypeSpecifierNonArray::TypeName(TypeName::new("float16_t").unwrap()));
+      assert_eq!(list.head.name, Some("half_scale".into()));
+    }
+    other => panic!("expected a declaration, got {:?}", other),
+  }
+}
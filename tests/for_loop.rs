@@ -0,0 +1,72 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Expr, ForInitStatement, IterationStatement, Statement};
+
+#[test]
+fn glsl_for_reconstructs_a_declaration_init_and_a_post_expr() {
+  let ist = glsl_for! {
+    for (int i = 0; i < 10; i++) {
+      accumulate(i);
+    }
+  };
+
+  match ist {
+    IterationStatement::For(ForInitStatement::Declaration(_), rest, body) => {
+      assert!(rest.condition.is_some());
+      assert!(rest.post_expr.is_some());
+
+      match *body {
+        Statement::Compound(compound) => assert_eq!(compound.statement_list.len(), 1),
+        other => panic!("expected a compound statement body, got {:?}", other),
+      }
+    }
+    other => panic!("expected a for loop, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_for_reconstructs_an_expression_init() {
+  let ist = glsl_for! {
+    for (i = 0; i < n; i++) {
+    }
+  };
+
+  match ist {
+    IterationStatement::For(ForInitStatement::Expression(Some(_)), ..) => {}
+    other => panic!("expected an expression init, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_for_reconstructs_comma_expressions_in_the_init_and_post_positions() {
+  let ist = glsl_for! {
+    for (i = 0, j = n; i < n; i++, j--) {
+    }
+  };
+
+  match ist {
+    IterationStatement::For(ForInitStatement::Expression(Some(init)), rest, _) => {
+      assert!(matches!(init, Expr::Comma(..)));
+      assert!(matches!(rest.post_expr.as_deref(), Some(Expr::Comma(..))));
+    }
+    other => panic!("expected a for loop with expression init, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_for_accepts_an_empty_condition_and_post_expr() {
+  let ist = glsl_for! {
+    for (;;) {
+    }
+  };
+
+  match ist {
+    IterationStatement::For(_, rest, _) => {
+      assert!(rest.condition.is_none());
+      assert!(rest.post_expr.is_none());
+    }
+    other => panic!("expected a for loop, got {:?}", other),
+  }
+}
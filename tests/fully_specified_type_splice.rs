@@ -0,0 +1,31 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{
+  Declaration, FullySpecifiedType, InitDeclaratorList, NonEmpty, StorageQualifier, TypeQualifier,
+  TypeQualifierSpec, TypeSpecifier, TypeSpecifierNonArray,
+};
+
+#[test]
+fn glsl_declaration_splices_a_whole_fully_specified_type_qualifier_included() {
+  let ty = FullySpecifiedType {
+    qualifier: Some(TypeQualifier {
+      qualifiers: NonEmpty(vec![TypeQualifierSpec::Storage(StorageQualifier::Const)]),
+    }),
+    ty: TypeSpecifier::from(TypeSpecifierNonArray::Float),
+  };
+
+  let expected = ty.clone();
+
+  let decl = glsl_declaration! {
+    #~#ty~ foo = 1.0;
+  };
+
+  match decl {
+    Declaration::InitDeclaratorList(InitDeclaratorList { head, .. }) => {
+      assert_eq!(head.ty, expected);
+    }
+    other => panic!("expected an init declarator list, got {:?}", other),
+  }
+}
@@ -0,0 +1,18 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Expr, FunIdentifier};
+
+#[test]
+fn glsl_expr_reconstructs_an_expression_based_function_identifier() {
+  let e = glsl_expr! { (funcs[0])(x) };
+
+  match e {
+    Expr::FunCall(FunIdentifier::Expr(inner), args) => {
+      assert!(matches!(*inner, Expr::Bracket(..)));
+      assert_eq!(args.len(), 1);
+    }
+    other => panic!("expected an expression-based function call, got {:?}", other),
+  }
+}
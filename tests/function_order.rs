@@ -0,0 +1,34 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::ExternalDeclaration;
+
+#[test]
+fn multiple_function_definitions_preserve_source_order() {
+  let tu = glsl! {
+    float square(float x) {
+      return x * x;
+    }
+
+    float sum_of_squares(float a, float b) {
+      return square(a) + square(b);
+    }
+
+    void main() {
+      float s = sum_of_squares(1.0, 2.0);
+    }
+  };
+
+  let names: Vec<_> = tu
+    .0
+     .0
+    .iter()
+    .map(|ed| match ed {
+      ExternalDeclaration::FunctionDefinition(def) => def.prototype.name.to_string(),
+      other => panic!("expected a function definition, got {:?}", other),
+    })
+    .collect();
+
+  assert_eq!(names, vec!["square", "sum_of_squares", "main"]);
+}
@@ -0,0 +1,58 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{
+  FunctionParameterDeclaration, FunctionParameterDeclarator, TypeSpecifier, TypeSpecifierNonArray,
+};
+
+fn float_param(name: &str) -> FunctionParameterDeclaration {
+  FunctionParameterDeclaration::Named(
+    None,
+    FunctionParameterDeclarator {
+      ty: TypeSpecifier::new(TypeSpecifierNonArray::Float),
+      ident: name.into(),
+    },
+  )
+}
+
+#[test]
+fn glsl_function_mixes_a_literal_parameter_with_a_spliced_vector() {
+  let extra = vec![float_param("b"), float_param("c")];
+
+  let fd = glsl_function! {
+    void f(float a, #(#extra)) {
+      a = a + b + c;
+    }
+  };
+
+  assert_eq!(fd.prototype.parameters.len(), 3);
+  assert_eq!(fd.prototype.parameters[0], float_param("a"));
+  assert_eq!(fd.prototype.parameters[1], float_param("b"));
+  assert_eq!(fd.prototype.parameters[2], float_param("c"));
+}
+
+#[test]
+fn glsl_function_accepts_an_empty_spliced_vector() {
+  let empty: Vec<FunctionParameterDeclaration> = Vec::new();
+
+  let fd = glsl_function! {
+    void g(#(#empty)) {
+    }
+  };
+
+  assert!(fd.prototype.parameters.is_empty());
+}
+
+#[test]
+fn glsl_function_accepts_a_splice_alongside_a_call_in_the_body() {
+  let extra = vec![float_param("b")];
+
+  let fd = glsl_function! {
+    void h(float a, #(#extra)) {
+      float r = max(a, b);
+    }
+  };
+
+  assert_eq!(fd.prototype.parameters.len(), 2);
+}
@@ -0,0 +1,69 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Declaration, ExternalDeclaration, StorageQualifier, TypeQualifierSpec};
+
+fn storage_qualifiers(ed: &ExternalDeclaration) -> Vec<&StorageQualifier> {
+  match ed {
+    ExternalDeclaration::Declaration(Declaration::Global(qualifier, idents)) => {
+      assert!(idents.is_empty());
+
+      qualifier
+        .qualifiers
+        .0
+        .iter()
+        .filter_map(|q| match q {
+          TypeQualifierSpec::Storage(s) => Some(s),
+          _ => None,
+        })
+        .collect()
+    }
+    other => panic!("expected a global qualifier declaration, got {:?}", other),
+  }
+}
+
+#[test]
+fn geometry_shader_input_primitive_layout_as_the_last_declaration_round_trips() {
+  // Regression test: a bare `layout(...) in;`/`out;` declaration with nothing after it used to
+  // fail to parse as part of a `TranslationUnit` at all (not merely leave trailing input), since
+  // `glsl`'s `external_declaration` treats its unconsumed trailing `;` as a stray separator and
+  // `cut`s on whatever follows -- which is nothing, for a geometry shader's input layout on its
+  // own.
+  let tu = glsl! {
+    layout(triangles) in;
+  };
+
+  assert_eq!(storage_qualifiers(&tu.0 .0[0]), vec![&StorageQualifier::In]);
+}
+
+#[test]
+fn geometry_shader_output_primitive_and_max_vertices_layout_round_trips() {
+  let tu = glsl! {
+    layout(triangle_strip, max_vertices = 3) out;
+  };
+
+  assert_eq!(storage_qualifiers(&tu.0 .0[0]), vec![&StorageQualifier::Out]);
+}
+
+#[test]
+fn geometry_shader_input_and_output_layouts_both_round_trip_in_the_same_shader() {
+  let tu = glsl! {
+    layout(triangles) in;
+    layout(triangle_strip, max_vertices = 3) out;
+
+    void main() {
+      EmitVertex();
+    }
+  };
+
+  assert_eq!(storage_qualifiers(&tu.0 .0[0]), vec![&StorageQualifier::In]);
+  assert_eq!(storage_qualifiers(&tu.0 .0[1]), vec![&StorageQualifier::Out]);
+
+  match &tu.0 .0[2] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "main".into());
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
@@ -0,0 +1,33 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::ExternalDeclaration;
+
+#[test]
+fn glsl_clean_strips_version_and_extension_directives() {
+  let tu = glsl_clean! {
+    #version 450
+    #extension GL_ARB_separate_shader_objects : enable
+
+    void main() {}
+  };
+
+  assert_eq!(tu.0 .0.len(), 1);
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "main".into());
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_clean_is_a_no_op_without_any_preprocessor_directives() {
+  let tu = glsl_clean! {
+    void main() {}
+  };
+
+  assert_eq!(tu.0 .0.len(), 1);
+}
@@ -0,0 +1,29 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::ExternalDeclaration;
+
+#[test]
+fn glsl_include_str_reads_parses_and_tokenizes_a_file() {
+  let tu = glsl_include_str!("fixtures/body.glsl");
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "main".into());
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_include_str_resolves_an_env_var_rooted_path() {
+  let tu = glsl_include_str!(env!("CARGO_MANIFEST_DIR"), "tests/fixtures/body.glsl");
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "main".into());
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
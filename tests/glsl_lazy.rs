@@ -0,0 +1,31 @@
+#![cfg(feature = "glsl-lazy")]
+
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::ExternalDeclaration;
+
+static SHADER: once_cell::sync::Lazy<glsl::syntax::TranslationUnit> = glsl_lazy! {
+  void main() {
+    int x = 1;
+  }
+};
+
+#[test]
+fn glsl_lazy_parses_the_same_as_glsl() {
+  match &SHADER.0 .0[0] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "main".into());
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_lazy_yields_the_same_unit_on_every_access() {
+  let first: &glsl::syntax::TranslationUnit = &SHADER;
+  let second: &glsl::syntax::TranslationUnit = &SHADER;
+
+  assert_eq!(first as *const _, second as *const _);
+}
@@ -0,0 +1,71 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+#[test]
+fn glsl_str_parses_a_version_pragma() {
+  let _ = glsl_str! {
+    "#version 330 core
+    void main() {
+    }"
+  };
+}
+
+#[test]
+fn glsl_expr_parses_a_bare_expression() {
+  let _ = glsl_expr! { a + b * c };
+}
+
+#[test]
+fn glsl_str_concatenates_adjacent_string_literals() {
+  let tu = glsl_str! {
+    "void main() {"
+    "}"
+  };
+
+  assert_eq!(tu.0 .0.len(), 1);
+}
+
+#[test]
+fn glsl_str_concatenates_a_version_pragma_and_body_split_across_literals() {
+  let tu = glsl_str! {
+    "#version 450\n"
+    "void main() {\n"
+    "}\n"
+  };
+
+  assert_eq!(tu.0 .0.len(), 2);
+}
+
+#[test]
+fn glsl_str_understands_include_directives() {
+  let tu = glsl_str! {
+    "#include <common.glsl>
+    #include \"util.glsl\"
+    void main() {
+    }"
+  };
+
+  assert_eq!(tu.0 .0.len(), 3);
+}
+
+#[test]
+fn glsl_str_normalizes_crlf_and_lone_cr_line_endings() {
+  let tu = glsl_str! {
+    "#version 330 core\r\nvoid main() {\r}"
+  };
+
+  assert_eq!(tu.0 .0.len(), 2);
+}
+
+#[test]
+fn glsl_str_understands_undef_and_error_inside_an_ifdef_guard() {
+  let _ = glsl_str! {
+    "#ifdef SOME_MISSING_MACRO
+    #error this branch should not be compiled
+    #endif
+    #undef SOME_MISSING_MACRO
+    void main() {
+    }"
+  };
+}
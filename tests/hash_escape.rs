@@ -0,0 +1,38 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{ExternalDeclaration, Preprocessor, PreprocessorDefine};
+
+#[test]
+fn a_double_hash_escapes_to_a_literal_hash_inside_a_define_body() {
+  let name = "compute_width";
+
+  let tu = glsl! {
+    #define STR(x) ##x
+
+    void #name() {
+    }
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Preprocessor(Preprocessor::Define(PreprocessorDefine::FunctionLike {
+      ident,
+      args,
+      value,
+    })) => {
+      assert_eq!(ident.as_str(), "STR");
+      assert_eq!(args.len(), 1);
+      assert_eq!(args[0].as_str(), "x");
+      assert_eq!(value, "#x");
+    }
+    other => panic!("expected a function-like #define, got {:?}", other),
+  }
+
+  match &tu.0 .0[1] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "compute_width".into());
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
@@ -0,0 +1,35 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Expr, FunIdentifier};
+
+#[test]
+fn splices_a_legal_identifier_into_a_function_call_name() {
+  let name = "compute";
+
+  let e = glsl_expr! { #name(1) };
+
+  match e {
+    Expr::FunCall(FunIdentifier::Identifier(ident), _) => {
+      assert_eq!(ident.as_str(), "compute");
+    }
+    other => panic!("expected a function call, got {:?}", other),
+  }
+}
+
+#[test]
+#[should_panic(expected = "is not a legal GLSL identifier")]
+fn splicing_a_reserved_word_as_a_function_call_name_panics() {
+  let name = "for";
+
+  let _ = glsl_expr! { #name(1) };
+}
+
+#[test]
+#[should_panic(expected = "is not a legal GLSL identifier")]
+fn splicing_a_digit_led_name_as_a_function_call_name_panics() {
+  let name = "1bad";
+
+  let _ = glsl_expr! { #name(1) };
+}
@@ -0,0 +1,33 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::ExternalDeclaration;
+
+#[test]
+fn glsl_str_accepts_a_bare_include_str() {
+  let tu = glsl_str! { include_str!("fixtures/body.glsl") };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "main".into());
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_str_accepts_a_literal_prelude_concatenated_with_an_include_str_body() {
+  let tu = glsl_str! {
+    concat!("#version 450 core\n", include_str!("fixtures/body.glsl"))
+  };
+
+  assert_eq!(tu.0 .0.len(), 2);
+
+  match &tu.0 .0[1] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "main".into());
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
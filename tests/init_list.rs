@@ -0,0 +1,29 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::Expr;
+
+#[test]
+fn glsl_init_list_reconstructs_head_and_tail() {
+  let l = glsl_init_list! { vec3 a = vec3(0.0), b, c[3] };
+
+  assert_eq!(l.head.name.as_ref().map(|i| i.as_str()), Some("a"));
+  assert!(l.head.initializer.is_some());
+
+  assert_eq!(l.tail.len(), 2);
+  assert_eq!(l.tail[0].ident.ident.as_str(), "b");
+  assert!(l.tail[0].ident.array_spec.is_none());
+  assert!(l.tail[0].initializer.is_none());
+
+  assert_eq!(l.tail[1].ident.ident.as_str(), "c");
+  assert!(l.tail[1].ident.array_spec.is_some());
+}
+
+#[test]
+fn glsl_init_list_accepts_holes() {
+  let value = Expr::FloatConst(1.0);
+  let l = glsl_init_list! { float x = #value };
+
+  assert!(l.head.initializer.is_some());
+}
@@ -0,0 +1,38 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::Initializer;
+
+#[test]
+fn glsl_initializer_parses_a_simple_expression() {
+  let init = glsl_initializer! { vec3(0.0) };
+
+  assert!(matches!(init, Initializer::Simple(..)));
+}
+
+#[test]
+fn glsl_initializer_parses_a_flat_list() {
+  let init = glsl_initializer! { { 1.0, 2.0, 3.0 } };
+
+  match init {
+    Initializer::List(list) => assert_eq!(list.0.len(), 3),
+    other => panic!("expected an initializer list, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_initializer_parses_a_nested_list() {
+  let init = glsl_initializer! { { { 1.0, 0.0 }, { 0.0, 1.0 } } };
+
+  match init {
+    Initializer::List(list) => {
+      assert_eq!(list.0.len(), 2);
+
+      for row in &list.0 {
+        assert!(matches!(row, Initializer::List(inner) if inner.0.len() == 2));
+      }
+    }
+    other => panic!("expected an initializer list, got {:?}", other),
+  }
+}
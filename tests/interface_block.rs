@@ -0,0 +1,32 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{ArraySpecifier, ArraySpecifierDimension, ArrayedIdentifier, ExternalDeclaration};
+
+#[test]
+fn interface_block_with_an_arrayed_instance_name_keeps_its_array_specifier() {
+  let tu = glsl! {
+    uniform Lights {
+      vec4 pos;
+    } lights[4];
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(glsl::syntax::Declaration::Block(block)) => {
+      assert_eq!(block.name, "Lights".into());
+      assert_eq!(
+        block.identifier,
+        Some(ArrayedIdentifier::new(
+          "lights",
+          Some(ArraySpecifier {
+            dimensions: glsl::syntax::NonEmpty(vec![ArraySpecifierDimension::ExplicitlySized(
+              Box::new(4.into())
+            )])
+          })
+        ))
+      );
+    }
+    other => panic!("expected an interface block declaration, got {:?}", other),
+  }
+}
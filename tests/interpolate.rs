@@ -0,0 +1,193 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::Identifier;
+
+#[test]
+fn interpolates_a_function_name() {
+  let name: Identifier = "compute_lighting".into();
+  let tu = glsl! {
+    void #name() {
+    }
+  };
+
+  match &tu.0 .0[0] {
+    glsl::syntax::ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "compute_lighting".into());
+    }
+    _ => panic!("expected a function definition"),
+  }
+}
+
+#[test]
+fn interpolates_a_function_name_from_a_rust_string() {
+  let name: String = String::from("compute_0");
+  let tu = glsl! {
+    void #name() {
+    }
+  };
+
+  match &tu.0 .0[0] {
+    glsl::syntax::ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "compute_0".into());
+    }
+    _ => panic!("expected a function definition"),
+  }
+}
+
+#[test]
+fn interpolates_a_function_name_at_a_call_site() {
+  let name: &str = "compute_0";
+  let tu = glsl! {
+    void main() {
+      #name(x);
+    }
+  };
+
+  match &tu.0 .0[0] {
+    glsl::syntax::ExternalDeclaration::FunctionDefinition(def) => {
+      match &def.statement.statement_list[0] {
+        glsl::syntax::Statement::Simple(st) => match **st {
+          glsl::syntax::SimpleStatement::Expression(Some(glsl::syntax::Expr::FunCall(
+            ref fun,
+            ..,
+          ))) => {
+            assert_eq!(
+              *fun,
+              glsl::syntax::FunIdentifier::Identifier("compute_0".into())
+            );
+          }
+          ref other => panic!("expected a function call expression, got {:?}", other),
+        },
+        _ => panic!("expected a simple statement"),
+      }
+    }
+    _ => panic!("expected a function definition"),
+  }
+}
+
+#[test]
+fn interpolates_a_bool_const_into_a_condition() {
+  let enabled = true;
+  let tu = glsl! {
+    void main() {
+      if (#enabled) {
+        discard;
+      }
+    }
+  };
+
+  match &tu.0 .0[0] {
+    glsl::syntax::ExternalDeclaration::FunctionDefinition(def) => {
+      match &def.statement.statement_list[0] {
+        glsl::syntax::Statement::Simple(st) => match **st {
+          glsl::syntax::SimpleStatement::Selection(ref sel) => {
+            assert_eq!(sel.cond, Box::new(glsl::syntax::Expr::BoolConst(true)));
+          }
+          ref other => panic!("expected a selection statement, got {:?}", other),
+        },
+        _ => panic!("expected a simple statement"),
+      }
+    }
+    _ => panic!("expected a function definition"),
+  }
+}
+
+#[test]
+fn interpolates_numeric_consts_into_an_initializer() {
+  let i: i32 = 7;
+  let f: f32 = 1.5;
+
+  let tu = glsl! {
+    void main() {
+      int a = #i;
+      float b = #f;
+    }
+  };
+
+  match &tu.0 .0[0] {
+    glsl::syntax::ExternalDeclaration::FunctionDefinition(def) => {
+      let init_of = |idx: usize| match &def.statement.statement_list[idx] {
+        glsl::syntax::Statement::Simple(st) => match **st {
+          glsl::syntax::SimpleStatement::Declaration(
+            glsl::syntax::Declaration::InitDeclaratorList(ref list),
+          ) => list.head.initializer.clone().expect("expected initializer"),
+          ref other => panic!("expected a declaration statement, got {:?}", other),
+        },
+        _ => panic!("expected a simple statement"),
+      };
+
+      assert_eq!(
+        init_of(0),
+        glsl::syntax::Initializer::Simple(Box::new(glsl::syntax::Expr::IntConst(7)))
+      );
+      assert_eq!(
+        init_of(1),
+        glsl::syntax::Initializer::Simple(Box::new(glsl::syntax::Expr::FloatConst(1.5)))
+      );
+    }
+    _ => panic!("expected a function definition"),
+  }
+}
+
+#[test]
+fn interpolates_an_array_size() {
+  const N: usize = 4;
+
+  let tu = glsl! {
+    float data[#N];
+  };
+
+  match &tu.0 .0[0] {
+    glsl::syntax::ExternalDeclaration::Declaration(glsl::syntax::Declaration::InitDeclaratorList(
+      list,
+    )) => {
+      let array_spec = list
+        .head
+        .array_specifier
+        .as_ref()
+        .expect("expected an array specifier");
+
+      assert_eq!(
+        array_spec.dimensions.0[0],
+        glsl::syntax::ArraySpecifierDimension::ExplicitlySized(Box::new(
+          glsl::syntax::Expr::UIntConst(N as u32)
+        ))
+      );
+    }
+    _ => panic!("expected a declaration"),
+  }
+}
+
+#[test]
+fn interpolates_an_expr_into_an_initializer() {
+  let lhs = glsl_expr! { a + b };
+
+  let tu = glsl! {
+    void main() {
+      float x = #lhs;
+    }
+  };
+
+  match &tu.0 .0[0] {
+    glsl::syntax::ExternalDeclaration::FunctionDefinition(def) => {
+      match &def.statement.statement_list[0] {
+        glsl::syntax::Statement::Simple(st) => match **st {
+          glsl::syntax::SimpleStatement::Declaration(
+            glsl::syntax::Declaration::InitDeclaratorList(ref list),
+          ) => {
+            let initializer = list.head.initializer.as_ref().expect("expected initializer");
+            assert_eq!(
+              initializer,
+              &glsl::syntax::Initializer::from(glsl_expr! { a + b })
+            );
+          }
+          _ => panic!("expected a declaration statement"),
+        },
+        _ => panic!("expected a simple statement"),
+      }
+    }
+    _ => panic!("expected a function definition"),
+  }
+}
@@ -0,0 +1,77 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Declaration, ExternalDeclaration, TypeQualifierSpec};
+
+#[test]
+fn invariant_qualifies_a_variable_declaration() {
+  let tu = glsl! {
+    invariant vec4 gl_Position;
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => {
+      let qualifiers = &list.head.ty.qualifier.as_ref().expect("expected a qualifier").qualifiers;
+      assert_eq!(qualifiers.0, vec![TypeQualifierSpec::Invariant]);
+    }
+    other => panic!("expected an init declarator list, got {:?}", other),
+  }
+}
+
+#[test]
+fn precise_qualifies_a_variable_declaration() {
+  let tu = glsl! {
+    precise float x;
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => {
+      let qualifiers = &list.head.ty.qualifier.as_ref().expect("expected a qualifier").qualifiers;
+      assert_eq!(qualifiers.0, vec![TypeQualifierSpec::Precise]);
+    }
+    other => panic!("expected an init declarator list, got {:?}", other),
+  }
+}
+
+#[test]
+fn bare_invariant_redeclaration_parses_as_a_nameless_init_declarator_list() {
+  // Regression test: `invariant gl_Position;` — an already-declared built-in re-marked
+  // `invariant` with no type of its own — used to fail with a spurious "unexpected trailing
+  // input: \";\"" error. `glsl`'s `global_declaration` parser (the grammar behind
+  // `Declaration::Global`) never consumes its own trailing `;`, and `Parse::parse` silently
+  // discards whatever a parser leaves unconsumed, so this crate's trailing-garbage check
+  // mistook that leftover `;` for garbage.
+  let tu = glsl! {
+    invariant gl_Position;
+
+    void main() {
+      gl_Position = vec4(1.0);
+    }
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => {
+      let qualifiers = &list.head.ty.qualifier.as_ref().expect("expected a qualifier").qualifiers;
+      assert_eq!(qualifiers.0, vec![TypeQualifierSpec::Invariant]);
+      assert_eq!(list.head.name, None);
+    }
+    other => panic!("expected an init declarator list, got {:?}", other),
+  }
+}
+
+#[test]
+fn bare_qualifier_with_no_identifier_parses_as_a_global_qualifier_declaration() {
+  let tu = glsl! {
+    layout(early_fragment_tests) in;
+
+    void main() {}
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::Global(_, idents)) => {
+      assert!(idents.is_empty());
+    }
+    other => panic!("expected a global qualifier declaration, got {:?}", other),
+  }
+}
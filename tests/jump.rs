@@ -0,0 +1,39 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Expr, JumpStatement};
+
+#[test]
+fn glsl_jump_parses_continue_break_and_discard() {
+  assert_eq!(glsl_jump! { continue; }, JumpStatement::Continue);
+  assert_eq!(glsl_jump! { break; }, JumpStatement::Break);
+  assert_eq!(glsl_jump! { discard; }, JumpStatement::Discard);
+}
+
+#[test]
+fn glsl_jump_parses_a_bare_return() {
+  assert_eq!(glsl_jump! { return; }, JumpStatement::Return(None));
+}
+
+#[test]
+fn glsl_jump_parses_a_return_with_a_value() {
+  match glsl_jump! { return x + 1.0; } {
+    JumpStatement::Return(Some(e)) => {
+      assert!(matches!(*e, Expr::Binary(..)));
+    }
+    other => panic!("expected a return with a value, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_jump_accepts_holes() {
+  let x = 1;
+
+  match glsl_jump! { return #x; } {
+    JumpStatement::Return(Some(e)) => {
+      assert_eq!(*e, Expr::IntConst(1));
+    }
+    other => panic!("expected a return with a value, got {:?}", other),
+  }
+}
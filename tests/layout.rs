@@ -0,0 +1,66 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Expr, LayoutQualifierSpec};
+
+#[test]
+fn glsl_layout_parses_identifiers_with_and_without_a_value() {
+  let l = glsl_layout! { layout(location = 0, std140) };
+
+  assert_eq!(l.ids.0.len(), 2);
+
+  match &l.ids.0[0] {
+    LayoutQualifierSpec::Identifier(name, value) => {
+      assert_eq!(name.as_str(), "location");
+      match value.as_deref() {
+        Some(Expr::IntConst(0)) => {}
+        other => panic!("expected Some(IntConst(0)), got {:?}", other),
+      }
+    }
+    other => panic!("expected an Identifier spec, got {:?}", other),
+  }
+
+  match &l.ids.0[1] {
+    LayoutQualifierSpec::Identifier(name, None) => assert_eq!(name.as_str(), "std140"),
+    other => panic!("expected a bare Identifier spec, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_layout_parses_shared() {
+  let l = glsl_layout! { layout(shared) };
+
+  match &l.ids.0[0] {
+    LayoutQualifierSpec::Shared => {}
+    other => panic!("expected Shared, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_layout_accepts_holes() {
+  let binding = Expr::IntConst(3);
+  let l = glsl_layout! { layout(binding = #binding) };
+
+  match &l.ids.0[0] {
+    LayoutQualifierSpec::Identifier(name, value) => {
+      assert_eq!(name.as_str(), "binding");
+      assert_eq!(value.as_deref(), Some(&Expr::IntConst(3)));
+    }
+    other => panic!("expected an Identifier spec, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_layout_accepts_a_raw_u32_hole_as_a_binding_value() {
+  let binding: u32 = 7;
+  let l = glsl_layout! { layout(binding = #binding) };
+
+  match &l.ids.0[0] {
+    LayoutQualifierSpec::Identifier(name, value) => {
+      assert_eq!(name.as_str(), "binding");
+      assert_eq!(value.as_deref(), Some(&Expr::UIntConst(7)));
+    }
+    other => panic!("expected an Identifier spec, got {:?}", other),
+  }
+}
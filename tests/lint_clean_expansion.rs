@@ -0,0 +1,81 @@
+//! `glsl!` and friends are expected to expand into code that's itself lint-clean, so a downstream
+//! crate can compile its own code with `#![deny(warnings)]` and not be tripped up by what this
+//! crate generates on its behalf. This file's crate-level `#![deny(warnings)]` turns any such
+//! regression (an unused import, an unreachable pattern, ...) into a build failure for this test
+//! alone, isolating it from the rest of the test suite's own code.
+#![deny(warnings)]
+
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+#[test]
+fn glsl_expands_without_warnings() {
+  let _ = glsl! {
+    #version 450 core
+
+    struct Light {
+      vec3 position;
+      vec3 color;
+    };
+
+    layout(std140, binding = 0) uniform Block {
+      mat4 mvp;
+    } block;
+
+    uniform Light lights[4];
+
+    const float kernel[3] = float[](0.1, 0.2, 0.7);
+
+    vec3 shade(vec3 n, Light l) {
+      return l.color * max(dot(n, l.position), 0.0);
+    }
+
+    void main() {
+      vec4 v = vec4(1.0);
+      vec3 rgb = v.rgb;
+      v.xyz = rgb;
+
+      for (int i = 0; i < 4; i++) {
+        if (i == 2) {
+          continue;
+        }
+      }
+
+      int x = -1;
+      int y = x < 0 ? -x : x;
+
+      switch (x) {
+        case 0:
+          break;
+        default:
+          break;
+      }
+    }
+  };
+}
+
+#[test]
+fn glsl_expr_expands_without_warnings() {
+  let _ = glsl_expr! { (1.0 + 2.0) * foo.xyz.r };
+  let _ = glsl_expr! { x |= y };
+}
+
+#[test]
+fn glsl_decls_expands_without_warnings() {
+  let _: Vec<glsl::syntax::ExternalDeclaration> = glsl_decls! {
+    void main() {
+    }
+  };
+}
+
+#[test]
+fn glsl_const_expands_without_warnings() {
+  glsl_const! {
+    fn shader() {
+      void main() {}
+    }
+  }
+
+  let _ = shader();
+}
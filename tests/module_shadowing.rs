@@ -0,0 +1,24 @@
+#[macro_use]
+extern crate glsl_quasiquote;
+
+// A local item named `glsl` shadows the crate root; the generated code must still resolve to the
+// real `glsl` dependency via an absolute `::glsl::...` path rather than this one.
+mod glsl {
+  pub struct NotTheCrate;
+}
+
+#[test]
+fn generated_code_is_immune_to_a_local_glsl_shadow() {
+  let _: glsl::NotTheCrate = glsl::NotTheCrate;
+
+  let tu = crate::glsl_quasiquote_test_target();
+
+  assert_eq!(tu.0 .0.len(), 1);
+}
+
+fn glsl_quasiquote_test_target() -> ::glsl::syntax::TranslationUnit {
+  glsl! {
+    void main() {
+    }
+  }
+}
@@ -0,0 +1,33 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{ArraySpecifierDimension, Declaration, ExternalDeclaration};
+
+#[test]
+fn a_two_dimensional_array_declaration_round_trips_both_dimensions() {
+  let tu = glsl! {
+    float a[2][3];
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => {
+      let dimensions = &list
+        .head
+        .array_specifier
+        .as_ref()
+        .expect("expected an array specifier")
+        .dimensions
+        .0;
+
+      assert_eq!(
+        dimensions.as_slice(),
+        &[
+          ArraySpecifierDimension::ExplicitlySized(Box::new(glsl::syntax::Expr::IntConst(2))),
+          ArraySpecifierDimension::ExplicitlySized(Box::new(glsl::syntax::Expr::IntConst(3))),
+        ]
+      );
+    }
+    other => panic!("expected a declaration, got {:?}", other),
+  }
+}
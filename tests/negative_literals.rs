@@ -0,0 +1,94 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Expr, UnaryOp};
+
+fn initializer_of(tu: &glsl::syntax::TranslationUnit, decl_index: usize) -> Expr {
+  match &tu.0 .0[0] {
+    glsl::syntax::ExternalDeclaration::FunctionDefinition(def) => {
+      match &def.statement.statement_list[decl_index] {
+        glsl::syntax::Statement::Simple(st) => match **st {
+          glsl::syntax::SimpleStatement::Declaration(
+            glsl::syntax::Declaration::InitDeclaratorList(ref list),
+          ) => match list.head.initializer.as_ref().expect("expected initializer") {
+            glsl::syntax::Initializer::Simple(e) => (**e).clone(),
+            other => panic!("expected a simple initializer, got {:?}", other),
+          },
+          ref other => panic!("expected a declaration statement, got {:?}", other),
+        },
+        _ => panic!("expected a simple statement"),
+      }
+    }
+    _ => panic!("expected a function definition"),
+  }
+}
+
+#[test]
+fn negative_literal_in_an_initializer_reparses_as_unary_minus() {
+  let tu = glsl! {
+    void main() {
+      float x = -1.0;
+    }
+  };
+
+  assert_eq!(
+    initializer_of(&tu, 0),
+    Expr::Unary(UnaryOp::Minus, Box::new(Expr::FloatConst(1.0)))
+  );
+}
+
+#[test]
+fn negative_literals_survive_as_function_call_arguments() {
+  let tu = glsl! {
+    void main() {
+      vec2 a = vec2(-1., 1.);
+    }
+  };
+
+  match initializer_of(&tu, 0) {
+    Expr::FunCall(_, args) => {
+      assert_eq!(
+        args[0],
+        Expr::Unary(UnaryOp::Minus, Box::new(Expr::FloatConst(1.0)))
+      );
+      assert_eq!(args[1], Expr::FloatConst(1.0));
+    }
+    other => panic!("expected a function call, got {:?}", other),
+  }
+}
+
+#[test]
+fn double_unary_minus_does_not_collapse_or_merge() {
+  let tu = glsl! {
+    void main() {
+      float y = - -1.0;
+    }
+  };
+
+  assert_eq!(
+    initializer_of(&tu, 0),
+    Expr::Unary(
+      UnaryOp::Minus,
+      Box::new(Expr::Unary(UnaryOp::Minus, Box::new(Expr::FloatConst(1.0))))
+    )
+  );
+}
+
+#[test]
+fn binary_minus_directly_adjacent_to_a_literal_stays_binary() {
+  let tu = glsl! {
+    void main() {
+      float v = 1-1.0;
+    }
+  };
+
+  assert_eq!(
+    initializer_of(&tu, 0),
+    Expr::Binary(
+      glsl::syntax::BinaryOp::Sub,
+      Box::new(Expr::IntConst(1)),
+      Box::new(Expr::FloatConst(1.0))
+    )
+  );
+}
@@ -0,0 +1,37 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::ExternalDeclaration;
+
+// `glsl_str!`'s literal handling goes through `syn::LitStr::value()` rather than a naive
+// `[1..len() - 1]` slice of the literal's raw text, so non-ASCII content (which would make such a
+// slice panic on a multi-byte UTF-8 boundary) is already handled correctly. Pinning that here.
+#[test]
+fn glsl_str_accepts_a_non_ascii_comment_without_panicking() {
+  let tu = glsl_str! {
+    r#"
+    // commentaire en français : ça fonctionne déjà
+    void main() {}
+    "#
+  };
+
+  assert_eq!(tu.0 .0.len(), 1);
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "main".into());
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_accepts_a_non_ascii_comment_without_panicking() {
+  let tu = glsl! {
+    // commentaire en français : ça fonctionne déjà
+    void main() {}
+  };
+
+  assert_eq!(tu.0 .0.len(), 1);
+}
@@ -0,0 +1,72 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{AssignmentOp, BinaryOp, Expr, UnaryOp};
+
+#[test]
+fn right_shift_does_not_merge_into_relational_operators() {
+  let e = glsl_expr! { a >> b };
+
+  match e {
+    Expr::Binary(BinaryOp::RShift, _, _) => {}
+    _ => panic!("expected a >> b to parse as a right shift, got {:?}", e),
+  }
+}
+
+#[test]
+fn left_shift_does_not_merge_into_relational_operators() {
+  let e = glsl_expr! { a << b };
+
+  match e {
+    Expr::Binary(BinaryOp::LShift, _, _) => {}
+    _ => panic!("expected a << b to parse as a left shift, got {:?}", e),
+  }
+}
+
+#[test]
+fn less_than_or_equal_stays_one_operator() {
+  let e = glsl_expr! { a <= b };
+
+  match e {
+    Expr::Binary(BinaryOp::LTE, _, _) => {}
+    _ => panic!("expected a <= b to parse as <=, got {:?}", e),
+  }
+}
+
+#[test]
+fn greater_than_or_equal_stays_one_operator() {
+  let e = glsl_expr! { a >= b };
+
+  match e {
+    Expr::Binary(BinaryOp::GTE, _, _) => {}
+    _ => panic!("expected a >= b to parse as >=, got {:?}", e),
+  }
+}
+
+#[test]
+fn less_than_followed_by_unary_minus_does_not_merge_into_a_decrement_arrow() {
+  let e = glsl_expr! { x < -1 };
+
+  match e {
+    Expr::Binary(BinaryOp::LT, _, ref rhs) => match **rhs {
+      Expr::Unary(UnaryOp::Minus, _) => {}
+      _ => panic!("expected the right-hand side to be a unary minus, got {:?}", rhs),
+    },
+    _ => panic!("expected x < -1 to parse as a relational comparison, got {:?}", e),
+  }
+}
+
+#[test]
+fn bitwise_or_assignment_resolves_to_a_fully_qualified_path() {
+  // Regression test: the generated code for `AssignmentOp::Or` used to emit a bare
+  // `AssignmentOp::Or` with no `::glsl::syntax::` prefix, which only compiled by accident when
+  // the caller happened to have that type in scope already — everywhere else, a plain `x |= y`
+  // failed with "cannot find type `AssignmentOp` in this scope".
+  let e = glsl_expr! { x |= y };
+
+  match e {
+    Expr::Assignment(_, AssignmentOp::Or, _) => {}
+    _ => panic!("expected x |= y to parse as a bitwise-or assignment, got {:?}", e),
+  }
+}
@@ -0,0 +1,39 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{
+  FunctionParameterDeclaration, StorageQualifier, TypeQualifierSpec, TypeSpecifierNonArray,
+};
+
+#[test]
+fn glsl_param_parses_a_named_parameter_with_a_qualifier() {
+  let p = glsl_param! { in const float x };
+
+  match p {
+    FunctionParameterDeclaration::Named(Some(qual), fpd) => {
+      assert_eq!(
+        qual.qualifiers.0,
+        vec![
+          TypeQualifierSpec::Storage(StorageQualifier::In),
+          TypeQualifierSpec::Storage(StorageQualifier::Const),
+        ]
+      );
+      assert_eq!(fpd.ty.ty, TypeSpecifierNonArray::Float);
+      assert_eq!(fpd.ident.ident.as_str(), "x");
+    }
+    other => panic!("expected a named parameter, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_param_parses_an_unnamed_parameter_with_no_qualifier() {
+  let p = glsl_param! { float };
+
+  match p {
+    FunctionParameterDeclaration::Unnamed(None, ty) => {
+      assert_eq!(ty.ty, TypeSpecifierNonArray::Float);
+    }
+    other => panic!("expected an unnamed parameter, got {:?}", other),
+  }
+}
@@ -0,0 +1,37 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Declaration, PrecisionQualifier, TypeSpecifier, TypeSpecifierNonArray};
+
+#[test]
+fn glsl_precision_reconstructs_a_precision_declaration() {
+  let decl = glsl_precision! { precision highp float; };
+
+  match decl {
+    Declaration::Precision(qual, ty) => {
+      assert_eq!(qual, PrecisionQualifier::High);
+      assert_eq!(ty, TypeSpecifier::from(TypeSpecifierNonArray::Float));
+    }
+    other => panic!("expected a precision declaration, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_precision_accepts_mediump_and_lowp() {
+  match glsl_precision! { precision mediump int; } {
+    Declaration::Precision(qual, ty) => {
+      assert_eq!(qual, PrecisionQualifier::Medium);
+      assert_eq!(ty, TypeSpecifier::from(TypeSpecifierNonArray::Int));
+    }
+    other => panic!("expected a precision declaration, got {:?}", other),
+  }
+
+  match glsl_precision! { precision lowp sampler2D; } {
+    Declaration::Precision(qual, ty) => {
+      assert_eq!(qual, PrecisionQualifier::Low);
+      assert_eq!(ty, TypeSpecifier::from(TypeSpecifierNonArray::Sampler2D));
+    }
+    other => panic!("expected a precision declaration, got {:?}", other),
+  }
+}
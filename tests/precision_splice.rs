@@ -0,0 +1,50 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{ExternalDeclaration, PrecisionQualifier, TypeQualifierSpec};
+
+#[test]
+fn splices_a_precision_qualifier_alone_into_a_function_return_type() {
+  let prec = PrecisionQualifier::Medium;
+
+  let tu = glsl! {
+    #<#prec> float foo() {
+      return 0.0;
+    }
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::FunctionDefinition(fd) => {
+      let qual = fd.prototype.ty.qualifier.as_ref().expect("expected a qualifier");
+      assert_eq!(
+        qual.qualifiers.0.as_slice(),
+        [TypeQualifierSpec::Precision(PrecisionQualifier::Medium)]
+      );
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
+
+#[test]
+fn splices_a_precision_qualifier_alongside_a_literal_one() {
+  let prec = PrecisionQualifier::High;
+
+  let tu = glsl! {
+    const #<#prec> float x = 1.0;
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(glsl::syntax::Declaration::InitDeclaratorList(list)) => {
+      let qual = list.head.ty.qualifier.as_ref().expect("expected a qualifier");
+      assert_eq!(
+        qual.qualifiers.0.as_slice(),
+        [
+          TypeQualifierSpec::Storage(glsl::syntax::StorageQualifier::Const),
+          TypeQualifierSpec::Precision(PrecisionQualifier::High),
+        ]
+      );
+    }
+    other => panic!("expected a declaration, got {:?}", other),
+  }
+}
@@ -0,0 +1,77 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{
+  Declaration, ExternalDeclaration, PrecisionQualifier, TypeQualifierSpec, TypeSpecifierNonArray,
+};
+
+#[test]
+fn precision_statement_round_trips() {
+  let tu = glsl! {
+    precision mediump int;
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::Precision(qual, ty)) => {
+      assert_eq!(qual, &PrecisionQualifier::Medium);
+      assert_eq!(ty.ty, TypeSpecifierNonArray::Int);
+    }
+    other => panic!("expected a precision declaration, got {:?}", other),
+  }
+}
+
+#[test]
+fn per_declaration_precision_qualifier_round_trips() {
+  let tu = glsl! {
+    void main() {
+      highp float x = 1.0;
+    }
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      let stmt = &def.statement.statement_list[0];
+      match stmt {
+        glsl::syntax::Statement::Simple(simple) => match &**simple {
+          glsl::syntax::SimpleStatement::Declaration(Declaration::InitDeclaratorList(list)) => {
+            let qualifiers = &list.head.ty.qualifier.as_ref().expect("expected a qualifier").qualifiers;
+            assert_eq!(qualifiers.0, vec![TypeQualifierSpec::Precision(PrecisionQualifier::High)]);
+            assert_eq!(list.head.ty.ty.ty, TypeSpecifierNonArray::Float);
+          }
+          other => panic!("expected an init declarator list, got {:?}", other),
+        },
+        other => panic!("expected a simple statement, got {:?}", other),
+      }
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
+
+#[test]
+fn all_three_precision_qualifiers_round_trip_as_a_single_shader() {
+  let tu = glsl! {
+    precision highp float;
+    precision mediump int;
+    precision lowp sampler2D;
+  };
+
+  let quals: Vec<_> = tu
+    .0
+     .0
+    .iter()
+    .map(|ed| match ed {
+      ExternalDeclaration::Declaration(Declaration::Precision(qual, _)) => qual.clone(),
+      other => panic!("expected a precision declaration, got {:?}", other),
+    })
+    .collect();
+
+  assert_eq!(
+    quals,
+    vec![
+      PrecisionQualifier::High,
+      PrecisionQualifier::Medium,
+      PrecisionQualifier::Low,
+    ]
+  );
+}
@@ -0,0 +1,47 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{StorageQualifier, TypeQualifier, TypeQualifierSpec};
+
+fn read_only() -> Option<TypeQualifier> {
+  Some(TypeQualifier {
+    qualifiers: glsl::syntax::NonEmpty(vec![TypeQualifierSpec::Storage(StorageQualifier::ReadOnly)]),
+  })
+}
+
+#[test]
+fn splices_a_present_qualifier() {
+  let qual = read_only();
+
+  let tu = glsl! {
+    #[#qual] float x;
+  };
+
+  match &tu.0 .0[0] {
+    glsl::syntax::ExternalDeclaration::Declaration(glsl::syntax::Declaration::InitDeclaratorList(
+      list,
+    )) => {
+      assert_eq!(list.head.ty.qualifier, read_only());
+    }
+    _ => panic!("expected a declaration"),
+  }
+}
+
+#[test]
+fn splices_an_absent_qualifier_to_nothing() {
+  let qual: Option<TypeQualifier> = None;
+
+  let tu = glsl! {
+    #[#qual] float x;
+  };
+
+  match &tu.0 .0[0] {
+    glsl::syntax::ExternalDeclaration::Declaration(glsl::syntax::Declaration::InitDeclaratorList(
+      list,
+    )) => {
+      assert_eq!(list.head.ty.qualifier, None);
+    }
+    _ => panic!("expected a declaration"),
+  }
+}
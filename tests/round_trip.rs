@@ -0,0 +1,89 @@
+//! A corpus of representative GLSL shaders, each parsed two ways — directly through
+//! [`glsl::parser::Parse`] and through [`glsl_str!`] — and checked for an identical AST. Unlike
+//! `glsl!`, `glsl_str!` parses its string literal's content as-is rather than reconstructing it
+//! from token spans (see `crate::holes` and `proc_macro_faithful_display`), so the two sides of
+//! this comparison are guaranteed to start from the exact same source text, isolating a real
+//! regression in `glsl`-to-Rust tokenization (float/swizzle/operator reconstruction, etc.) from
+//! one in `faithful_display`'s span-based reconstruction, which the other `tests/*.rs` files
+//! already exercise through `glsl!` directly.
+
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::parser::Parse;
+use glsl::syntax::TranslationUnit;
+
+macro_rules! round_trip {
+  ($name:ident, $src:literal) => {
+    #[test]
+    fn $name() {
+      let direct: TranslationUnit =
+        Parse::parse($src).expect("corpus shader failed to parse directly through `glsl`");
+      let quoted: TranslationUnit = glsl_str! { $src };
+
+      assert_eq!(
+        direct, quoted,
+        "glsl_str! produced a different AST than parsing `{}` directly",
+        $src
+      );
+    }
+  };
+}
+
+round_trip!(empty_main, "void main() {}");
+
+round_trip!(
+  leading_dot_and_trailing_dot_floats,
+  "void main() { float a = .3; float b = 3.; float c = 1.5e10; }"
+);
+
+round_trip!(
+  swizzle_reads_and_writes,
+  "void main() { vec4 v = vec4(1.0); vec3 rgb = v.rgb; v.xyz = rgb; }"
+);
+
+round_trip!(
+  shift_and_relational_operators,
+  "void main() { int a = (1 << 2) >> 1; bool b = a <= 3 && a >= 1; }"
+);
+
+round_trip!(
+  unary_minus_and_ternary,
+  "void main() { int x = -1; int y = x < 0 ? -x : x; }"
+);
+
+round_trip!(
+  arrays_and_initializer_lists,
+  "void main() { const float k[3] = float[](0.1, 0.2, 0.7); }"
+);
+
+round_trip!(
+  struct_with_shared_type_fields,
+  "struct S { float a, b, c; vec2 uv; }; void main() {}"
+);
+
+round_trip!(
+  version_and_extension_pragmas,
+  "#version 450 core\n#extension GL_foo_bar : require\nvoid main() {}\n"
+);
+
+round_trip!(
+  function_with_parameters_and_return,
+  "float add(float a, float b) { return a + b; }"
+);
+
+round_trip!(
+  control_flow_if_for_while,
+  "void main() { for (int i = 0; i < 4; i++) { if (i == 2) { continue; } } int i = 0; while (i < 4) { i++; } }"
+);
+
+round_trip!(
+  layout_qualified_uniform_block,
+  "layout(std140, binding = 0) uniform Block { mat4 mvp; } block;"
+);
+
+round_trip!(
+  precision_statement_and_qualifier,
+  "precision highp float; highp vec3 normal(vec3 n) { return normalize(n); }"
+);
@@ -0,0 +1,34 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Declaration, ExternalDeclaration, TypeSpecifierNonArray};
+
+fn declared_type(ed: &ExternalDeclaration) -> &TypeSpecifierNonArray {
+  match ed {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => &list.head.ty.ty.ty,
+    other => panic!("expected an init declarator list, got {:?}", other),
+  }
+}
+
+#[test]
+fn atomic_uint_image_and_shadow_sampler_types_round_trip() {
+  let tu = glsl! {
+    layout(binding = 0, offset = 0) uniform atomic_uint counter;
+    uniform image2D img;
+    uniform uimageBuffer u_buf;
+    uniform sampler2DShadow shadow_map;
+  };
+
+  let types: Vec<_> = tu.0 .0.iter().map(declared_type).collect();
+
+  assert_eq!(
+    types,
+    vec![
+      &TypeSpecifierNonArray::AtomicUInt,
+      &TypeSpecifierNonArray::Image2D,
+      &TypeSpecifierNonArray::UImageBuffer,
+      &TypeSpecifierNonArray::Sampler2DShadow,
+    ]
+  );
+}
@@ -0,0 +1,45 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Expr, SelectionRestStatement};
+
+#[test]
+fn glsl_selection_parses_a_bare_if() {
+  let sst = glsl_selection! {
+    if (c) a();
+  };
+
+  assert_eq!(*sst.cond, Expr::Variable("c".into()));
+
+  match sst.rest {
+    SelectionRestStatement::Statement(_) => {}
+    other => panic!("expected a bare if, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_selection_parses_an_if_else() {
+  let sst = glsl_selection! {
+    if (c) {
+      a();
+    } else {
+      b();
+    }
+  };
+
+  match sst.rest {
+    SelectionRestStatement::Else(..) => {}
+    other => panic!("expected an if/else, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_selection_accepts_holes() {
+  let cond = Expr::BoolConst(true);
+  let sst = glsl_selection! {
+    if (#cond) a();
+  };
+
+  assert_eq!(*sst.cond, Expr::BoolConst(true));
+}
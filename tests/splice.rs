@@ -0,0 +1,101 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{ExternalDeclaration, TranslationUnit};
+
+fn named_fn(name: &str) -> ExternalDeclaration {
+  let name: glsl::syntax::Identifier = name.into();
+  let tu = glsl! { void #name() {} };
+  tu.0 .0.into_iter().next().unwrap()
+}
+
+#[test]
+fn splices_external_declarations_preserving_order() {
+  let decls = vec![named_fn("a"), named_fn("b")];
+
+  let tu = glsl! {
+    #(#decls)
+
+    void main() {
+    }
+  };
+
+  assert_eq!(tu.0 .0.len(), 3);
+  assert_eq!(tu.0 .0[0], named_fn("a"));
+  assert_eq!(tu.0 .0[1], named_fn("b"));
+
+  match &tu.0 .0[2] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "main".into());
+    }
+    _ => panic!("expected a function definition"),
+  }
+}
+
+#[test]
+fn splicing_an_empty_vec_is_a_no_op() {
+  let decls: Vec<ExternalDeclaration> = Vec::new();
+
+  let tu = glsl! {
+    #(#decls)
+
+    void main() {
+    }
+  };
+
+  assert_eq!(tu.0 .0.len(), 1);
+}
+
+// A `#(#decls)` splice just extends a `Vec<ExternalDeclaration>` from whatever the spliced
+// value's `IntoIterator<Item = ExternalDeclaration>` impl yields, and `glsl::syntax::TranslationUnit`
+// already implements that -- so splicing a whole base shader in to flatten its declarations into
+// the enclosing one needs no dedicated support, just this splice used with a `TranslationUnit`
+// value instead of a `Vec<ExternalDeclaration>`.
+#[test]
+fn splices_a_whole_translation_unit_flattening_its_declarations() {
+  let base: TranslationUnit = glsl! {
+    void a() {}
+    void b() {}
+  };
+
+  let tu = glsl! {
+    #(#base)
+
+    void main() {
+    }
+  };
+
+  assert_eq!(tu.0 .0.len(), 3);
+  assert_eq!(tu.0 .0[0], named_fn("a"));
+  assert_eq!(tu.0 .0[1], named_fn("b"));
+}
+
+#[test]
+fn splices_multiple_translation_units_preserving_relative_order_with_literal_declarations() {
+  let base1: TranslationUnit = glsl! { void a() {} };
+  let base2: TranslationUnit = glsl! { void c() {} };
+
+  let tu = glsl! {
+    #(#base1)
+
+    void b() {}
+
+    #(#base2)
+
+    void main() {
+    }
+  };
+
+  let names: Vec<&str> = tu
+    .0
+     .0
+    .iter()
+    .map(|ed| match ed {
+      ExternalDeclaration::FunctionDefinition(def) => def.prototype.name.as_str(),
+      _ => panic!("expected a function definition"),
+    })
+    .collect();
+
+  assert_eq!(names, vec!["a", "b", "c", "main"]);
+}
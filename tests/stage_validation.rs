@@ -0,0 +1,20 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+#[test]
+fn glsl_stage_accepts_a_vertex_position_write() {
+  let _ = glsl_stage!(vertex, {
+    void main() {
+      gl_Position = vec4(0.0, 0.0, 0.0, 1.0);
+    }
+  });
+}
+
+#[test]
+fn glsl_stage_accepts_a_fragment_shader_with_no_vertex_only_builtins() {
+  let _ = glsl_stage!(fragment, {
+    void main() {
+    }
+  });
+}
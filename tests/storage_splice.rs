@@ -0,0 +1,54 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{
+  ExternalDeclaration, SingleDeclaration, StorageQualifier, TypeQualifierSpec,
+};
+
+fn storage_qualifiers_of(tu: &glsl::syntax::TranslationUnit) -> Vec<StorageQualifier> {
+  match &(tu.0).0[0] {
+    ExternalDeclaration::Declaration(glsl::syntax::Declaration::InitDeclaratorList(
+      glsl::syntax::InitDeclaratorList {
+        head: SingleDeclaration { ty, .. },
+        ..
+      },
+    )) => ty
+      .qualifier
+      .as_ref()
+      .map(|q| {
+        q.qualifiers
+          .0
+          .iter()
+          .filter_map(|spec| match spec {
+            TypeQualifierSpec::Storage(s) => Some(s.clone()),
+            _ => None,
+          })
+          .collect()
+      })
+      .unwrap_or_default(),
+    other => panic!("expected an init declarator list, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_storage_splice_generates_the_in_direction() {
+  let dir = StorageQualifier::In;
+
+  let tu = glsl! {
+    #|#dir| vec3 normal;
+  };
+
+  assert_eq!(storage_qualifiers_of(&tu), vec![StorageQualifier::In]);
+}
+
+#[test]
+fn glsl_storage_splice_generates_the_out_direction() {
+  let dir = StorageQualifier::Out;
+
+  let tu = glsl! {
+    #|#dir| vec3 normal;
+  };
+
+  assert_eq!(storage_qualifiers_of(&tu), vec![StorageQualifier::Out]);
+}
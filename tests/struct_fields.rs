@@ -0,0 +1,71 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{
+  ArrayedIdentifier, StructFieldSpecifier, StructSpecifier, TypeSpecifier, TypeSpecifierNonArray,
+};
+
+fn field(name: &str, ty: TypeSpecifierNonArray) -> StructFieldSpecifier {
+  StructFieldSpecifier {
+    qualifier: None,
+    ty: TypeSpecifier::from(ty),
+    identifiers: glsl::syntax::NonEmpty(vec![ArrayedIdentifier::new(name, None)]),
+  }
+}
+
+fn struct_of(tu: &glsl::syntax::TranslationUnit) -> StructSpecifier {
+  match &tu.0 .0[0] {
+    glsl::syntax::ExternalDeclaration::Declaration(glsl::syntax::Declaration::InitDeclaratorList(
+      list,
+    )) => match &list.head.ty.ty.ty {
+      TypeSpecifierNonArray::Struct(s) => s.clone(),
+      other => panic!("expected a struct type specifier, got {:?}", other),
+    },
+    other => panic!("expected a declaration, got {:?}", other),
+  }
+}
+
+#[test]
+fn splices_struct_fields_mixed_with_literal_ones() {
+  let generated = vec![
+    field("uv", TypeSpecifierNonArray::Vec2),
+    field("normal", TypeSpecifierNonArray::Vec3),
+  ];
+
+  let tu = glsl! {
+    struct Vertex {
+      vec4 position;
+      #(#generated)
+      float weight;
+    };
+  };
+
+  let s = struct_of(&tu);
+
+  assert_eq!(s.fields.0.len(), 4);
+  assert_eq!(s.fields.0[0], field("position", TypeSpecifierNonArray::Vec4));
+  assert_eq!(s.fields.0[1], field("uv", TypeSpecifierNonArray::Vec2));
+  assert_eq!(s.fields.0[2], field("normal", TypeSpecifierNonArray::Vec3));
+  assert_eq!(s.fields.0[3], field("weight", TypeSpecifierNonArray::Float));
+}
+
+#[test]
+fn a_struct_whose_fields_are_entirely_generated_tokenizes_correctly() {
+  let generated = vec![
+    field("a", TypeSpecifierNonArray::Float),
+    field("b", TypeSpecifierNonArray::Int),
+  ];
+
+  let tu = glsl! {
+    struct Generated {
+      #(#generated)
+    };
+  };
+
+  let s = struct_of(&tu);
+
+  assert_eq!(s.fields.0.len(), 2);
+  assert_eq!(s.fields.0[0], field("a", TypeSpecifierNonArray::Float));
+  assert_eq!(s.fields.0[1], field("b", TypeSpecifierNonArray::Int));
+}
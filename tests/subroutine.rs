@@ -0,0 +1,49 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{
+  Declaration, ExternalDeclaration, StorageQualifier, TypeName, TypeQualifierSpec,
+  TypeSpecifierNonArray,
+};
+
+#[test]
+fn a_subroutine_type_and_a_subroutine_uniform_variable_round_trip() {
+  let decls: Vec<ExternalDeclaration> = glsl_decls! {
+    subroutine void ColorFunc(float a);
+    subroutine(ColorFunc) uniform ColorFunc chooseColor;
+  };
+
+  match &decls[0] {
+    ExternalDeclaration::Declaration(Declaration::FunctionPrototype(fp)) => {
+      assert_eq!(fp.name.as_str(), "ColorFunc");
+      assert_eq!(
+        fp.ty.qualifier.as_ref().unwrap().qualifiers.0,
+        vec![TypeQualifierSpec::Storage(StorageQualifier::Subroutine(
+          vec![]
+        ))]
+      );
+    }
+    other => panic!("expected a function prototype, got {:?}", other),
+  }
+
+  match &decls[1] {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => {
+      assert_eq!(
+        list.head.ty.qualifier.as_ref().unwrap().qualifiers.0,
+        vec![
+          TypeQualifierSpec::Storage(StorageQualifier::Subroutine(vec![TypeName(
+            "ColorFunc".to_owned()
+          )])),
+          TypeQualifierSpec::Storage(StorageQualifier::Uniform),
+        ]
+      );
+      assert_eq!(
+        list.head.ty.ty.ty,
+        TypeSpecifierNonArray::TypeName(TypeName("ColorFunc".to_owned()))
+      );
+      assert_eq!(list.head.name.as_ref().unwrap().as_str(), "chooseColor");
+    }
+    other => panic!("expected an init declarator list, got {:?}", other),
+  }
+}
@@ -0,0 +1,68 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{CaseLabel, Statement};
+
+#[test]
+fn glsl_switch_reconstructs_case_and_default_labels() {
+  let sst = glsl_switch! {
+    switch (mode) {
+      case 0:
+        a();
+        break;
+      default:
+        b();
+    }
+  };
+
+  assert_eq!(sst.body.len(), 5);
+
+  match &sst.body[0] {
+    Statement::Simple(st) => match **st {
+      glsl::syntax::SimpleStatement::CaseLabel(CaseLabel::Case(ref e)) => {
+        assert_eq!(**e, glsl::syntax::Expr::IntConst(0));
+      }
+      ref other => panic!("expected a case label, got {:?}", other),
+    },
+    other => panic!("expected a simple statement, got {:?}", other),
+  }
+
+  match &sst.body[3] {
+    Statement::Simple(st) => match **st {
+      glsl::syntax::SimpleStatement::CaseLabel(CaseLabel::Def) => {}
+      ref other => panic!("expected a default label, got {:?}", other),
+    },
+    other => panic!("expected a simple statement, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_switch_accepts_an_empty_body() {
+  let sst = glsl_switch! {
+    switch (mode) {
+    }
+  };
+
+  assert_eq!(sst.body.len(), 0);
+}
+
+#[test]
+fn glsl_switch_accepts_a_default_only_body() {
+  let sst = glsl_switch! {
+    switch (mode) {
+      default:
+        b();
+    }
+  };
+
+  assert_eq!(sst.body.len(), 2);
+
+  match &sst.body[0] {
+    Statement::Simple(st) => match **st {
+      glsl::syntax::SimpleStatement::CaseLabel(CaseLabel::Def) => {}
+      ref other => panic!("expected a default label, got {:?}", other),
+    },
+    other => panic!("expected a simple statement, got {:?}", other),
+  }
+}
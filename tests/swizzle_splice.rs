@@ -0,0 +1,40 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::Expr;
+
+#[test]
+fn interpolates_a_swizzle_name_from_a_rust_str() {
+  let swizzle: &str = "rgb";
+  let e = glsl_expr! { color.#swizzle };
+
+  match e {
+    Expr::Dot(base, member) => {
+      assert_eq!(*base, Expr::Variable("color".into()));
+      assert_eq!(member, "rgb".into());
+    }
+    other => panic!("expected a Dot expression, got {:?}", other),
+  }
+}
+
+#[test]
+fn interpolates_a_struct_field_name() {
+  let field: String = String::from("position");
+  let e = glsl_expr! { block.#field };
+
+  match e {
+    Expr::Dot(base, member) => {
+      assert_eq!(*base, Expr::Variable("block".into()));
+      assert_eq!(member, "position".into());
+    }
+    other => panic!("expected a Dot expression, got {:?}", other),
+  }
+}
+
+#[test]
+#[should_panic(expected = "is not a legal GLSL identifier")]
+fn a_spliced_member_name_that_is_not_a_legal_identifier_panics() {
+  let swizzle: &str = "rg!b";
+  let _ = glsl_expr! { color.#swizzle };
+}
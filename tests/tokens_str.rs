@@ -0,0 +1,27 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+#[test]
+fn glsl_tokens_str_contains_the_generated_translation_unit_construction() {
+  let tokens: &str = glsl_tokens_str! {
+    void main() {}
+  };
+
+  assert!(tokens.contains("TranslationUnit"));
+  assert!(tokens.contains("main"));
+}
+
+#[test]
+fn glsl_tokens_str_renders_a_spliced_hole_as_the_call_site_identifier() {
+  // Only consumed as *source text* fed into the macro (the rendered result is an inert string,
+  // never real code), so this never actually reads the variable back out, unlike every other hole
+  // in this crate.
+  let _name: glsl::syntax::Identifier = "bar".into();
+
+  let tokens: &str = glsl_tokens_str! {
+    void #_name() {}
+  };
+
+  assert!(tokens.contains("_name"));
+}
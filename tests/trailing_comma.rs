@@ -0,0 +1,53 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Declaration, Expr, ExternalDeclaration, StructSpecifier, TypeSpecifierNonArray};
+
+fn struct_of(tu: &glsl::syntax::TranslationUnit) -> StructSpecifier {
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => {
+      match &list.head.ty.ty.ty {
+        TypeSpecifierNonArray::Struct(s) => s.clone(),
+        other => panic!("expected a struct type specifier, got {:?}", other),
+      }
+    }
+    other => panic!("expected a declaration, got {:?}", other),
+  }
+}
+
+#[test]
+fn tolerates_a_trailing_comma_in_a_function_parameter_list() {
+  let tu = glsl! {
+    void f(float a, float b,) {
+    }
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::FunctionDefinition(fd) => {
+      assert_eq!(fd.prototype.parameters.len(), 2);
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
+
+#[test]
+fn tolerates_a_trailing_comma_in_a_struct_fields_shared_type_declarator_list() {
+  let tu = glsl! {
+    struct S {
+      float x, y, z,;
+    };
+  };
+
+  let s = struct_of(&tu);
+
+  assert_eq!(s.fields.0.len(), 1);
+  assert_eq!(s.fields.0[0].identifiers.0.len(), 3);
+}
+
+#[test]
+fn tolerates_a_trailing_comma_in_a_call_argument_list() {
+  let e = glsl_expr! { max(a, b,) };
+
+  assert!(matches!(e, Expr::FunCall(..)));
+}
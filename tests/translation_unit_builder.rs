@@ -0,0 +1,63 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Declaration, ExternalDeclaration, FunctionDefinition, TranslationUnit};
+
+// This crate is `proc-macro = true`, so it can't export a `TranslationUnitBuilder` itself — see
+// the "Assembling a `TranslationUnit` from individual macros" section of the crate docs. This is
+// the kind of small, ordinary-Rust wrapper a caller would write in their own crate around
+// `glsl_declaration!`/`glsl_function!`'s output; defined here only to exercise that pattern
+// end to end.
+#[derive(Default)]
+struct TranslationUnitBuilder {
+  declarations: Vec<ExternalDeclaration>,
+}
+
+impl TranslationUnitBuilder {
+  fn new() -> Self {
+    Self::default()
+  }
+
+  fn declaration(mut self, decl: Declaration) -> Self {
+    self.declarations.push(ExternalDeclaration::Declaration(decl));
+    self
+  }
+
+  fn function(mut self, def: FunctionDefinition) -> Self {
+    self.declarations.push(ExternalDeclaration::FunctionDefinition(def));
+    self
+  }
+
+  fn build(self) -> TranslationUnit {
+    TranslationUnit::from_non_empty_iter(self.declarations).expect("at least one declaration")
+  }
+}
+
+#[test]
+fn builds_a_small_shader_from_individually_quoted_pieces() {
+  let tu = TranslationUnitBuilder::new()
+    .declaration(glsl_declaration! { uniform float time; })
+    .function(glsl_function! {
+      void main() {
+        gl_FragColor = vec4(time);
+      }
+    })
+    .build();
+
+  assert_eq!(tu.0 .0.len(), 2);
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => {
+      assert_eq!(list.head.name, Some("time".into()));
+    }
+    other => panic!("expected an init declarator list, got {:?}", other),
+  }
+
+  match &tu.0 .0[1] {
+    ExternalDeclaration::FunctionDefinition(def) => {
+      assert_eq!(def.prototype.name, "main".into());
+    }
+    other => panic!("expected a function definition, got {:?}", other),
+  }
+}
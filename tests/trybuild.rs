@@ -0,0 +1,10 @@
+// A `compile_error!` triggered inside a macro can't be exercised from an ordinary `#[test]` in the
+// same build -- the whole test binary would fail to compile -- so the `check-duplicate-functions`
+// feature's rejecting case is driven out-of-process with `trybuild` instead. See
+// `tests/duplicate_functions.rs` for the (much more common) case where the feature is off.
+#[test]
+#[cfg(feature = "check-duplicate-functions")]
+fn duplicate_function_definition_is_rejected() {
+  let t = trybuild::TestCases::new();
+  t.compile_fail("tests/ui/duplicate_functions.rs");
+}
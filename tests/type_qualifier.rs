@@ -0,0 +1,62 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{Expr, LayoutQualifierSpec, StorageQualifier, TypeQualifierSpec};
+
+#[test]
+fn glsl_type_qualifier_combines_layout_and_storage_qualifiers() {
+  let q = glsl_type_qualifier! { layout(std430) buffer readonly };
+
+  match &q.qualifiers.0[..] {
+    [
+      TypeQualifierSpec::Layout(layout),
+      TypeQualifierSpec::Storage(StorageQualifier::Buffer),
+      TypeQualifierSpec::Storage(StorageQualifier::ReadOnly),
+    ] => match &layout.ids.0[0] {
+      LayoutQualifierSpec::Identifier(name, None) => assert_eq!(name.as_str(), "std430"),
+      other => panic!("expected a bare Identifier spec, got {:?}", other),
+    },
+    other => panic!("expected [Layout, Storage(Buffer), Storage(ReadOnly)], got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_type_qualifier_parses_a_single_storage_qualifier() {
+  let q = glsl_type_qualifier! { uniform };
+
+  assert_eq!(q.qualifiers.0, vec![TypeQualifierSpec::Storage(StorageQualifier::Uniform)]);
+}
+
+#[test]
+fn layout_shared_is_distinct_from_the_shared_storage_qualifier() {
+  let layout = glsl_type_qualifier! { layout(shared) };
+
+  assert_eq!(
+    layout.qualifiers.0,
+    vec![TypeQualifierSpec::Layout(glsl::syntax::LayoutQualifier {
+      ids: glsl::syntax::NonEmpty::from_non_empty_iter(vec![LayoutQualifierSpec::Shared]).unwrap(),
+    })]
+  );
+
+  let storage = glsl_type_qualifier! { shared };
+
+  assert_eq!(storage.qualifiers.0, vec![TypeQualifierSpec::Storage(StorageQualifier::Shared)]);
+}
+
+#[test]
+fn glsl_type_qualifier_accepts_holes_inside_its_layout_qualifier() {
+  let binding = Expr::IntConst(3);
+  let q = glsl_type_qualifier! { layout(binding = #binding) uniform };
+
+  match &q.qualifiers.0[0] {
+    TypeQualifierSpec::Layout(layout) => match &layout.ids.0[0] {
+      LayoutQualifierSpec::Identifier(name, value) => {
+        assert_eq!(name.as_str(), "binding");
+        assert_eq!(value.as_deref(), Some(&Expr::IntConst(3)));
+      }
+      other => panic!("expected an Identifier spec, got {:?}", other),
+    },
+    other => panic!("expected a Layout spec, got {:?}", other),
+  }
+}
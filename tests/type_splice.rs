@@ -0,0 +1,56 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{
+  Declaration, ExternalDeclaration, StructFieldSpecifier, TypeSpecifier, TypeSpecifierNonArray,
+};
+
+#[test]
+fn splices_a_type_specifier_computed_in_rust_into_a_struct_field() {
+  let elem = TypeSpecifier::from(TypeSpecifierNonArray::Vec4);
+
+  let tu = glsl! {
+    struct Buf {
+      #{#elem} data;
+    };
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => {
+      match &list.head.ty.ty.ty {
+        TypeSpecifierNonArray::Struct(s) => {
+          assert_eq!(
+            s.fields.0[0],
+            StructFieldSpecifier {
+              qualifier: None,
+              ty: TypeSpecifier::from(TypeSpecifierNonArray::Vec4),
+              identifiers: glsl::syntax::NonEmpty(vec![glsl::syntax::ArrayedIdentifier::new(
+                "data", None
+              )]),
+            }
+          );
+        }
+        other => panic!("expected a struct type specifier, got {:?}", other),
+      }
+    }
+    other => panic!("expected a declaration, got {:?}", other),
+  }
+}
+
+#[test]
+fn a_spliced_type_specifier_also_works_on_a_plain_variable_declaration() {
+  let elem = TypeSpecifier::from(TypeSpecifierNonArray::Int);
+
+  let tu = glsl! {
+    #{#elem} counter;
+  };
+
+  match &tu.0 .0[0] {
+    ExternalDeclaration::Declaration(Declaration::InitDeclaratorList(list)) => {
+      assert_eq!(list.head.ty.ty, TypeSpecifier::from(TypeSpecifierNonArray::Int));
+      assert_eq!(list.head.name, Some("counter".into()));
+    }
+    other => panic!("expected a declaration, got {:?}", other),
+  }
+}
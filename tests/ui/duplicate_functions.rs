@@ -0,0 +1,10 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+fn main() {
+  glsl! {
+    float add(float a, float b) { return a + b; }
+    float add(float a, float b) { return a + b; }
+  };
+}
@@ -0,0 +1,80 @@
+extern crate glsl;
+#[macro_use]
+extern crate glsl_quasiquote;
+
+use glsl::syntax::{
+  ExternalDeclaration, Preprocessor, PreprocessorVersion, PreprocessorVersionProfile,
+};
+
+fn version_of(tu: &glsl::syntax::TranslationUnit) -> u16 {
+  match &(tu.0).0[0] {
+    ExternalDeclaration::Preprocessor(Preprocessor::Version(PreprocessorVersion {
+      version,
+      ..
+    })) => *version,
+    other => panic!("expected a #version directive, got {:?}", other),
+  }
+}
+
+fn profile_of(tu: &glsl::syntax::TranslationUnit) -> Option<PreprocessorVersionProfile> {
+  match &(tu.0).0[0] {
+    ExternalDeclaration::Preprocessor(Preprocessor::Version(PreprocessorVersion {
+      profile,
+      ..
+    })) => profile.clone(),
+    other => panic!("expected a #version directive, got {:?}", other),
+  }
+}
+
+#[test]
+fn glsl_str_carries_no_profile_through_a_bare_version_pragma() {
+  let tu = glsl_str! {
+    "#version 450
+    void main() {
+    }"
+  };
+
+  assert_eq!(profile_of(&tu), None);
+}
+
+#[test]
+fn glsl_str_carries_the_core_profile_through_a_version_pragma() {
+  let tu = glsl_str! {
+    "#version 450 core
+    void main() {
+    }"
+  };
+
+  assert_eq!(profile_of(&tu), Some(PreprocessorVersionProfile::Core));
+}
+
+#[test]
+fn glsl_splices_the_version_number() {
+  let v: u16 = 330;
+  let tu = glsl! {
+    #version #v core
+    void main() {
+    }
+  };
+
+  assert_eq!(version_of(&tu), 330);
+}
+
+#[test]
+fn glsl_version_splice_switches_between_330_and_450() {
+  let v330: u16 = 330;
+  let tu330 = glsl! {
+    #version #v330 core
+    void main() {
+    }
+  };
+  assert_eq!(version_of(&tu330), 330);
+
+  let v450: u16 = 450;
+  let tu450 = glsl! {
+    #version #v450 core
+    void main() {
+    }
+  };
+  assert_eq!(version_of(&tu450), 450);
+}